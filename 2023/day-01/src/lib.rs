@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+
+use aoc_common::{lines, Error, Result, Solution};
+
+pub struct Day01;
+
+aoc_common::export_plugin!(Day01);
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 1;
+
+    type Parsed = Vec<String>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(lines(input))
+    }
+
+    fn part1(lines: &Self::Parsed) -> Result<i32> {
+        let mut sum = 0;
+        for line in lines {
+            let mut str_num = String::new();
+            let mut last_seen_char = 'X';
+            for c in line.chars() {
+                if c.is_ascii_digit() {
+                    if str_num.chars().count() == 0 {
+                        str_num.push(c);
+                    }
+                    last_seen_char = c;
+                }
+            }
+            if str_num.chars().count() == 0 {
+                return Err(Error::Unsolvable(format!("no digits in line {line:?}")));
+            }
+            str_num.push(last_seen_char);
+            let this_num = str_num
+                .parse::<i32>()
+                .expect("string should hold a two digit integer");
+            sum += this_num;
+        }
+        Ok(sum)
+    }
+
+    fn part2(lines: &Self::Parsed) -> Result<i32> {
+        let digit_words: HashMap<&str, char> = [
+            ("one", '1'),
+            ("two", '2'),
+            ("three", '3'),
+            ("four", '4'),
+            ("five", '5'),
+            ("six", '6'),
+            ("seven", '7'),
+            ("eight", '8'),
+            ("nine", '9'),
+        ]
+        .iter()
+        .cloned()
+        .collect();
+        let mut sum = 0;
+        for line in lines {
+            let mut digits = Vec::new();
+            let mut c = line.chars().peekable();
+            while let Some(&current_char) = c.peek() {
+                if current_char.is_ascii_digit() {
+                    digits.push(current_char);
+                    c.next();
+                } else {
+                    let mut lookahead = c.clone();
+                    let mut lookahead_count = 0;
+                    let mut digit_word = String::new();
+                    while let Some(&next_char) = lookahead.peek() {
+                        lookahead_count += 1;
+                        if !next_char.is_alphabetic() || lookahead_count > 6 {
+                            break;
+                        }
+                        digit_word.push(next_char);
+                        lookahead.next();
+                        if let Some(&digit) = digit_words.get(digit_word.as_str()) {
+                            digits.push(digit);
+                            break;
+                        }
+                    }
+                    c.next();
+                }
+            }
+            if digits.is_empty() {
+                return Err(Error::Unsolvable(format!("no digits in line {line:?}")));
+            }
+            let mut str_num = String::new();
+            str_num.push(digits[0]);
+            str_num.push(digits[digits.len() - 1]);
+            let this_num = str_num
+                .parse::<i32>()
+                .expect("string should hold a two digit integer");
+            sum += this_num;
+        }
+        Ok(sum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::example_answer;
+
+    use super::*;
+
+    #[test]
+    fn part1_example_case() {
+        let parsed = Day01::parse_file("input/p1.example.txt").expect("example input should parse");
+        let result = Day01::part1(&parsed).expect("example should have an answer");
+        let expected =
+            example_answer("input/p1.example.txt", 1).expect("example should have a part 1 answer");
+        assert_eq!(result.to_string(), expected);
+    }
+
+    #[test]
+    fn part2_example_case() {
+        let parsed = Day01::parse_file("input/p2.example.txt").expect("example input should parse");
+        let result = Day01::part2(&parsed).expect("example should have an answer");
+        let expected =
+            example_answer("input/p2.example.txt", 2).expect("example should have a part 2 answer");
+        assert_eq!(result.to_string(), expected);
+    }
+}
@@ -0,0 +1,143 @@
+use std::cmp;
+
+use aoc_common::{
+    parse::{finish, IResult},
+    Result, Solution,
+};
+use nom::{
+    branch::alt,
+    character::complete::{self, line_ending},
+    combinator::cut,
+    multi::separated_list1,
+    sequence::{delimited, separated_pair},
+    Parser,
+};
+use nom_supreme::{tag::complete::tag, ParserExt};
+
+/// The cubes of each colour shown in one handful.
+#[derive(Debug, Default)]
+pub struct CubeSet {
+    pub red: i32,
+    pub green: i32,
+    pub blue: i32,
+}
+
+#[derive(Debug)]
+pub struct Game {
+    pub id: i32,
+    pub subsets: Vec<CubeSet>,
+}
+
+fn cube_set(input: &str) -> IResult<'_, CubeSet> {
+    let colour = alt((tag("red"), tag("green"), tag("blue"))).context("colour");
+    // every count follows a separator, so a bad one is an error rather than the end of the list
+    let count = cut(separated_pair(complete::i32, tag(" "), colour));
+    separated_list1(tag(", "), count)
+        .map(|counts| {
+            let mut cubes = CubeSet::default();
+            for (num_cubes, colour) in counts {
+                match colour {
+                    "red" => cubes.red = num_cubes,
+                    "green" => cubes.green = num_cubes,
+                    _ => cubes.blue = num_cubes,
+                }
+            }
+            cubes
+        })
+        .parse(input)
+}
+
+fn game(input: &str) -> IResult<'_, Game> {
+    let (input, id) = delimited(tag("Game "), complete::i32, tag(": "))
+        .context("game id")
+        .parse(input)?;
+    let (input, subsets) = separated_list1(tag("; "), cube_set).parse(input)?;
+    Ok((input, Game { id, subsets }))
+}
+
+pub struct Day02;
+
+aoc_common::export_plugin!(Day02);
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 2;
+
+    type Parsed = Vec<Game>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        finish(input, separated_list1(line_ending, game))
+    }
+
+    fn part1(games: &Self::Parsed) -> Result<i32> {
+        const RED: i32 = 12;
+        const GREEN: i32 = 13;
+        const BLUE: i32 = 14;
+        let total = games
+            .iter()
+            .filter(|game| {
+                game.subsets
+                    .iter()
+                    .all(|cubes| cubes.red <= RED && cubes.green <= GREEN && cubes.blue <= BLUE)
+            })
+            .map(|game| game.id)
+            .sum::<i32>();
+        Ok(total)
+    }
+
+    fn part2(games: &Self::Parsed) -> Result<i32> {
+        let power = games
+            .iter()
+            .map(|game| {
+                let mut max_red = 0;
+                let mut max_green = 0;
+                let mut max_blue = 0;
+                for cubes in &game.subsets {
+                    max_red = cmp::max(max_red, cubes.red);
+                    max_green = cmp::max(max_green, cubes.green);
+                    max_blue = cmp::max(max_blue, cubes.blue);
+                }
+                max_red * max_green * max_blue
+            })
+            .sum::<i32>();
+        Ok(power)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::example_answer;
+
+    use super::*;
+
+    #[test]
+    fn part1_example_case() {
+        let parsed = Day02::parse_file("input/p1.example.txt").expect("example input should parse");
+        let result = Day02::part1(&parsed).expect("example should have an answer");
+        let expected =
+            example_answer("input/p1.example.txt", 1).expect("example should have a part 1 answer");
+        assert_eq!(result.to_string(), expected);
+    }
+
+    #[test]
+    fn part2_example_case() {
+        let parsed = Day02::parse_file("input/p2.example.txt").expect("example input should parse");
+        let result = Day02::part2(&parsed).expect("example should have an answer");
+        let expected =
+            example_answer("input/p2.example.txt", 2).expect("example should have a part 2 answer");
+        assert_eq!(result.to_string(), expected);
+    }
+
+    #[test]
+    fn unknown_colour() {
+        let input = "Game 1: 3 blue, 4 red\nGame 2: 1 red, 2 purple\n";
+        let err = Day02::parse(input).expect_err("purple is not a cube colour");
+        let message = err.to_string();
+        assert!(
+            message.starts_with("<input>:2:18: expected \"red\" or \"green\" or \"blue\""),
+            "{message}"
+        );
+    }
+}
@@ -0,0 +1,155 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::{Error, Grid, Pos, Result, Solution};
+
+fn is_symbol(c: char) -> bool {
+    c != '.' && !c.is_ascii_digit()
+}
+
+fn is_star(c: char) -> bool {
+    c == '*'
+}
+
+// the grid takes any run of digits, so a part number can be too long for the answer's type
+fn part_number(digits: &str) -> Result<i32> {
+    digits
+        .parse()
+        .map_err(|_| Error::Unsolvable(format!("part number {digits} doesn't fit in i32")))
+}
+
+fn too_big(what: &str) -> Error {
+    Error::Unsolvable(format!("the {what} add up to more than i32::MAX"))
+}
+
+pub struct Day03;
+
+aoc_common::export_plugin!(Day03);
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 3;
+
+    type Parsed = Grid<char>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Grid::parse(input, "any character", Some)
+    }
+
+    fn part1(schematic: &Self::Parsed) -> Result<i32> {
+        let mut current_num = String::new();
+        let mut part_number_sum: i32 = 0;
+        for (i, row) in schematic.rows().enumerate() {
+            let mut part_number_found = false;
+            for (j, &current_char) in row.iter().enumerate() {
+                let is_digit = current_char.is_ascii_digit();
+                if is_digit {
+                    part_number_found |= schematic
+                        .neighbours8((i, j))
+                        .any(|pos| is_symbol(schematic[pos]));
+                    current_num.push(current_char);
+                }
+                if !is_digit || j == row.len() - 1 {
+                    if part_number_found {
+                        part_number_sum = part_number_sum
+                            .checked_add(part_number(&current_num)?)
+                            .ok_or_else(|| too_big("part numbers"))?;
+                    }
+                    current_num.clear();
+                    part_number_found = false;
+                }
+            }
+        }
+        Ok(part_number_sum)
+    }
+
+    fn part2(schematic: &Self::Parsed) -> Result<i32> {
+        let mut current_num = String::new();
+        let mut possible_gear_parts: HashMap<Pos, Vec<i32>> = HashMap::new();
+        for (i, row) in schematic.rows().enumerate() {
+            let mut gear_candidates: HashSet<Pos> = HashSet::new();
+            for (j, &current_char) in row.iter().enumerate() {
+                let is_digit = current_char.is_ascii_digit();
+                if is_digit {
+                    current_num.push(current_char);
+                    gear_candidates.extend(
+                        schematic
+                            .neighbours8((i, j))
+                            .filter(|pos| is_star(schematic[*pos])),
+                    );
+                }
+                if !is_digit || j == row.len() - 1 {
+                    for part in &gear_candidates {
+                        let part_num = part_number(&current_num)?;
+                        possible_gear_parts.entry(*part).or_default().push(part_num);
+                    }
+                    current_num.clear();
+                    gear_candidates.clear();
+                }
+            }
+        }
+        let mut gear_ratio_sum: i32 = 0;
+        for part_nums in possible_gear_parts.values() {
+            if part_nums.len() == 2 {
+                gear_ratio_sum = part_nums[0]
+                    .checked_mul(part_nums[1])
+                    .and_then(|ratio| gear_ratio_sum.checked_add(ratio))
+                    .ok_or_else(|| too_big("gear ratios"))?;
+            }
+        }
+        Ok(gear_ratio_sum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::example_answer;
+
+    use super::*;
+
+    #[test]
+    fn part1_example_case() {
+        let parsed = Day03::parse_file("input/p1.example.txt").expect("example input should parse");
+        let result = Day03::part1(&parsed).expect("example should have an answer");
+        let expected =
+            example_answer("input/p1.example.txt", 1).expect("example should have a part 1 answer");
+        assert_eq!(result.to_string(), expected);
+    }
+
+    #[test]
+    fn part1_example2_case() {
+        let parsed =
+            Day03::parse_file("input/p1.example2.txt").expect("example input should parse");
+        let result = Day03::part1(&parsed).expect("example should have an answer");
+        let expected = example_answer("input/p1.example2.txt", 1)
+            .expect("example should have a part 1 answer");
+        assert_eq!(result.to_string(), expected);
+    }
+
+    #[test]
+    fn part2_example_case() {
+        let parsed = Day03::parse_file("input/p1.example.txt").expect("example input should parse");
+        let result = Day03::part2(&parsed).expect("example should have an answer");
+        let expected =
+            example_answer("input/p1.example.txt", 2).expect("example should have a part 2 answer");
+        assert_eq!(result.to_string(), expected);
+    }
+
+    #[test]
+    fn part_numbers_too_long_for_i32() {
+        let schematic = Day03::parse("12345678901*\n...........2\n").expect("input should parse");
+        let err = Day03::part1(&schematic).expect_err("12345678901 doesn't fit in i32");
+        assert_eq!(
+            err.to_string(),
+            "part number 12345678901 doesn't fit in i32"
+        );
+        Day03::part2(&schematic).expect_err("12345678901 doesn't fit in i32");
+        let schematic = Day03::parse("2147483647*2\n").expect("input should parse");
+        let err = Day03::part2(&schematic).expect_err("the ratio doesn't fit in i32");
+        assert_eq!(
+            err.to_string(),
+            "the gear ratios add up to more than i32::MAX"
+        );
+    }
+}
@@ -0,0 +1,128 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::{
+    parse::{finish, IResult},
+    Result, Solution,
+};
+use nom::{
+    bytes::complete::take_till,
+    character::complete::{self, line_ending, multispace0, multispace1},
+    multi::separated_list1,
+    sequence::{preceded, terminated},
+    Parser,
+};
+use nom_supreme::{tag::complete::tag, ParserExt};
+
+#[derive(Debug)]
+pub struct Scratchcard {
+    pub id: u32,
+    pub winning_nums: HashSet<u32>,
+    pub my_nums: HashSet<u32>,
+}
+
+impl Scratchcard {
+    /// How many of my numbers are winning numbers.
+    pub fn match_count(&self) -> u32 {
+        self.my_nums
+            .intersection(&self.winning_nums)
+            .count()
+            .try_into()
+            .expect("should fit in u32")
+    }
+
+    /// A point for the first match, doubled for each match after it.
+    pub fn get_points(&self) -> u32 {
+        match self.match_count() {
+            0 => 0,
+            x => u32::pow(2, x - 1),
+        }
+    }
+}
+
+fn num_set(input: &str) -> IResult<'_, HashSet<u32>> {
+    let (input, set) = preceded(multispace0, separated_list1(multispace1, complete::u32))(input)?;
+    Ok((input, set.into_iter().collect()))
+}
+
+fn card(input: &str) -> IResult<'_, Scratchcard> {
+    let (input, _) = preceded(tag("Card"), multispace1)(input)?;
+    let (input, id) = terminated(complete::u32, tag(":"))
+        .context("card id")
+        .parse(input)?;
+    let (input, winning_section) = take_till(|c| c == '|')(input)?;
+    let (_, winning_nums) = num_set.context("winning numbers").parse(winning_section)?;
+    let (input, _) = preceded(tag("|"), multispace1)(input)?;
+    let (input, my_nums) = num_set.context("my numbers").parse(input)?;
+    Ok((
+        input,
+        Scratchcard {
+            id,
+            winning_nums,
+            my_nums,
+        },
+    ))
+}
+
+fn parse_cards(input: &str) -> IResult<'_, Vec<Scratchcard>> {
+    let (input, cards) = separated_list1(line_ending, card)(input)?;
+    Ok((input, cards))
+}
+
+pub struct Day04;
+
+aoc_common::export_plugin!(Day04);
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 4;
+
+    type Parsed = Vec<Scratchcard>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        finish(input, parse_cards)
+    }
+
+    fn part1(cards: &Self::Parsed) -> Result<u32> {
+        Ok(cards.iter().map(|card| card.get_points()).sum::<u32>())
+    }
+
+    fn part2(cards: &Self::Parsed) -> Result<u32> {
+        let mut card_counts: HashMap<u32, u32> = HashMap::new();
+        for card in cards.iter() {
+            let matches = card.match_count();
+            let copies = *card_counts.entry(card.id).or_insert(1);
+            for i in 0..matches {
+                let card_id = card.id + i + 1;
+                *card_counts.entry(card_id).or_insert(1) += copies;
+            }
+        }
+        Ok(card_counts.values().sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::example_answer;
+
+    use super::*;
+
+    #[test]
+    fn part1_example_case() {
+        let parsed = Day04::parse_file("input/p1.example.txt").expect("example input should parse");
+        let result = Day04::part1(&parsed).expect("example should have an answer");
+        let expected =
+            example_answer("input/p1.example.txt", 1).expect("example should have a part 1 answer");
+        assert_eq!(result.to_string(), expected);
+    }
+
+    #[test]
+    fn part2_example_case() {
+        let parsed = Day04::parse_file("input/p1.example.txt").expect("example input should parse");
+        let result = Day04::part2(&parsed).expect("example should have an answer");
+        let expected =
+            example_answer("input/p1.example.txt", 2).expect("example should have a part 2 answer");
+        assert_eq!(result.to_string(), expected);
+    }
+}
@@ -0,0 +1,300 @@
+use std::ops::Range;
+
+use aoc_common::{
+    interval::{PiecewiseMap, RangeSet},
+    parse::{finish, IResult},
+    Error, Explore, Result, Solution,
+};
+use nom::{
+    bytes::complete::take_until,
+    character::complete::{self, line_ending, multispace1, space0},
+    combinator::{cut, peek, verify},
+    multi::{many1, separated_list1},
+    sequence::{terminated, tuple},
+    Parser,
+};
+use nom_supreme::{tag::complete::tag, ParserExt};
+
+// the rules state that a value without an entry maps to itself, which is what a
+// `PiecewiseMap` does outside its pieces
+pub type Mapping = PiecewiseMap<u64>;
+
+#[derive(Debug)]
+pub struct Directory {
+    // one mapping for each section, in the order they're applied
+    pub maps: Vec<Mapping>,
+}
+
+impl Directory {
+    /// Follows a seed through every section to its location.
+    pub fn map_seed(&self, seed: u64) -> u64 {
+        self.maps.iter().fold(seed, |value, map| map.map(value))
+    }
+
+    pub fn min_dst(&self, seeds: &[u64]) -> u64 {
+        seeds
+            .iter()
+            .map(|seed| self.map_seed(*seed))
+            .min()
+            .unwrap_or(0)
+    }
+
+    // pushes whole ranges through each section, so the size of the ranges doesn't matter
+    pub fn min_dst_from_range(&self, seed_ranges: &[Range<u64>]) -> u64 {
+        let seeds = seed_ranges.iter().cloned().collect::<RangeSet<u64>>();
+        self.maps
+            .iter()
+            .fold(seeds, |values, map| map.map_set(&values))
+            .min()
+            .unwrap_or(0)
+    }
+}
+
+#[derive(Debug)]
+pub struct Almanac {
+    pub seeds: Vec<u64>,
+    pub directory: Directory,
+}
+
+impl Almanac {
+    // part 2 reads the seed list as (start, length) pairs, where part 1 takes any number of
+    // single seeds
+    pub fn seed_ranges(&self) -> Result<Vec<Range<u64>>> {
+        if !self.seeds.len().is_multiple_of(2) {
+            return Err(Error::Unsolvable(format!(
+                "{} seeds can't be read as (start, length) pairs",
+                self.seeds.len()
+            )));
+        }
+        self.seeds
+            .chunks_exact(2)
+            .map(|pair| {
+                let end = pair[0].checked_add(pair[1]).ok_or_else(|| {
+                    Error::Unsolvable(format!(
+                        "seed range {} + {} runs past u64::MAX",
+                        pair[0], pair[1]
+                    ))
+                })?;
+                Ok(pair[0]..end)
+            })
+            .collect()
+    }
+}
+
+fn num(input: &str) -> IResult<'_, u64> {
+    let (input, num) = terminated(complete::u64, space0)(input)?;
+    Ok((input, num))
+}
+
+// a range running past u64::MAX can't be mapped, so that's an error once the three numbers
+// are there rather than the end of the list
+fn piece(input: &str) -> IResult<'_, (Range<u64>, u64)> {
+    let fits = |(dst, src, n): &(u64, u64, u64)| {
+        dst.checked_add(*n).is_some() && src.checked_add(*n).is_some()
+    };
+    let (input, (dst, src, n)) = peek(tuple((num, num, num)))
+        .precedes(cut(verify(tuple((num, num, num)), fits)).context("range within u64"))
+        .parse(input)?;
+    Ok((input, (src..src + n, dst)))
+}
+
+fn mapping(input: &str) -> IResult<'_, Mapping> {
+    let (input, pieces) = take_until(":")
+        .precedes(tag(":"))
+        .precedes(multispace1)
+        .precedes(separated_list1(line_ending, piece))
+        .context("map")
+        .parse(input)?;
+    Ok((input, pieces.into_iter().collect()))
+}
+
+fn almanac(input: &str) -> IResult<'_, Almanac> {
+    let (input, seeds) = tag("seeds: ")
+        .precedes(separated_list1(multispace1, complete::u64))
+        .context("seeds")
+        .parse(input)?;
+    let (input, maps) = many1(mapping)(input)?;
+    Ok((
+        input,
+        Almanac {
+            seeds,
+            directory: Directory { maps },
+        },
+    ))
+}
+
+pub struct Day05;
+
+aoc_common::export_plugin!(Day05);
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 5;
+
+    type Parsed = Almanac;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        finish(input, almanac)
+    }
+
+    fn part1(almanac: &Self::Parsed) -> Result<u64> {
+        Ok(almanac.directory.min_dst(&almanac.seeds))
+    }
+
+    fn part2(almanac: &Self::Parsed) -> Result<u64> {
+        Ok(almanac
+            .directory
+            .min_dst_from_range(&almanac.seed_ranges()?))
+    }
+}
+
+impl Explore for Day05 {
+    const COMMANDS: &'static [(&'static str, &'static str)] = &[(
+        "map <seed>",
+        "follow a seed through each map to its location",
+    )];
+
+    fn explore(almanac: &Almanac, command: &str, arg: &str) -> Option<Result<String>> {
+        if command != "map" {
+            return None;
+        }
+        let Ok(seed) = arg.parse::<u64>() else {
+            return Some(Err(Error::InvalidArgument(format!("{arg:?} isn't a seed"))));
+        };
+        let steps = almanac
+            .directory
+            .maps
+            .iter()
+            .scan(seed, |value, map| {
+                *value = map.map(*value);
+                Some(value.to_string())
+            })
+            .collect::<Vec<_>>();
+        Some(Ok(format!("{seed} -> {}", steps.join(" -> "))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::example_answer;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn part1_example_case() {
+        let parsed = Day05::parse_file("input/p1.example.txt").expect("example input should parse");
+        let result = Day05::part1(&parsed).expect("example should have an answer");
+        let expected =
+            example_answer("input/p1.example.txt", 1).expect("example should have a part 1 answer");
+        assert_eq!(result.to_string(), expected);
+    }
+
+    #[test]
+    fn part2_example_case() {
+        let parsed = Day05::parse_file("input/p1.example.txt").expect("example input should parse");
+        let result = Day05::part2(&parsed).expect("example should have an answer");
+        let expected =
+            example_answer("input/p1.example.txt", 2).expect("example should have a part 2 answer");
+        assert_eq!(result.to_string(), expected);
+    }
+
+    // small numbers so pieces overlap each other and the seed ranges often
+    fn mapping() -> impl Strategy<Value = Mapping> {
+        prop::collection::vec((0..200u64, 1..50u64, 0..200u64), 0..5).prop_map(|pieces| {
+            pieces
+                .into_iter()
+                .map(|(src, len, dst)| (src..src + len, dst))
+                .collect()
+        })
+    }
+
+    fn almanac() -> impl Strategy<Value = Almanac> {
+        (
+            prop::collection::vec((0..200u64, 1..50u64), 1..4),
+            prop::collection::vec(mapping(), 1..8),
+        )
+            .prop_map(|(seed_ranges, maps)| Almanac {
+                seeds: seed_ranges
+                    .into_iter()
+                    .flat_map(|(start, len)| [start, len])
+                    .collect(),
+                directory: Directory { maps },
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn range_mapping_matches_each_seed(almanac in almanac()) {
+            let ranges = almanac.seed_ranges().expect("generated ranges should fit in u64");
+            let seeds = ranges.iter().cloned().flatten().collect::<Vec<_>>();
+            prop_assert_eq!(
+                almanac.directory.min_dst_from_range(&ranges),
+                almanac.directory.min_dst(&seeds)
+            );
+        }
+    }
+
+    #[test]
+    fn range_past_u64_max() {
+        let err = Day05::parse("seeds: 1 2\n\nseed-to-soil map:\n0 18446744073709551615 2\n")
+            .expect_err("the source range overflows");
+        assert!(err.to_string().starts_with("<input>:4:1: "), "{err}");
+        assert!(err.to_string().contains("range within u64"), "{err}");
+    }
+
+    #[test]
+    fn odd_seed_count() {
+        let almanac = Day05::parse(
+            "seeds: 79 14 55
+
+seed-to-soil map:
+50 98 2
+",
+        )
+        .expect("any number of seeds should parse");
+        assert_eq!(
+            Day05::part1(&almanac).expect("part 1 maps single seeds"),
+            14
+        );
+        let err = Day05::part2(&almanac).expect_err("the last seed has no length");
+        assert_eq!(
+            err.to_string(),
+            "3 seeds can't be read as (start, length) pairs"
+        );
+    }
+
+    #[test]
+    fn seed_range_past_u64_max() {
+        let almanac = Day05::parse(
+            "seeds: 18446744073709551615 5
+
+seed-to-soil map:
+50 98 2
+",
+        )
+        .expect("input should parse");
+        let err = Day05::part2(&almanac).expect_err("the seed range overflows");
+        assert_eq!(
+            err.to_string(),
+            "seed range 18446744073709551615 + 5 runs past u64::MAX"
+        );
+    }
+
+    #[test]
+    fn map_command_follows_a_seed() {
+        let parsed = Day05::parse_file("input/p1.example.txt").expect("example input should parse");
+        let trace = Day05::explore(&parsed, "map", "79").expect("day 5 should have map");
+        assert_eq!(
+            trace.expect("79 is a seed"),
+            "79 -> 81 -> 81 -> 81 -> 74 -> 78 -> 78 -> 82"
+        );
+        assert!(matches!(
+            Day05::explore(&parsed, "map", "seventy"),
+            Some(Err(Error::InvalidArgument(_)))
+        ));
+        assert!(Day05::explore(&parsed, "hand", "32T3K").is_none());
+    }
+}
@@ -0,0 +1,221 @@
+use aoc_common::{
+    parse::{finish, IResult},
+    Error, Result, Solution,
+};
+use nom::{
+    character::complete::{self, line_ending, multispace1},
+    combinator::{recognize, verify},
+    multi::separated_list1,
+    sequence::preceded,
+    Parser,
+};
+use nom_supreme::{tag::complete::tag, ParserExt};
+
+#[derive(Debug)]
+pub struct Record {
+    pub time: u64,
+    pub distance: u64,
+}
+
+impl Record {
+    pub fn count_ways_to_beat(&self) -> u64 {
+        // for each millisecond that the button on the boat is held for, the boat will travel at
+        // that many millimetres per second i.e. hold for 2ms -> boat speed is 2mm/ms
+        // therefore, we don't need to check 0 or the max time as these will result in moving 0mm
+        // which would only beat a negative distance (not possible)
+        let mut count = 0;
+        for i in 1..self.time {
+            let post_button_time = self.time - i;
+            // the product of two u64s always fits in a u128
+            let distance_can_travel = u128::from(post_button_time) * u128::from(i);
+            if distance_can_travel > u128::from(self.distance) {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    // the distance for a hold of `h` is h * (time - h), which rises until half the time and then
+    // falls symmetrically, so binary search for the shortest winning hold and mirror it
+    pub fn count_ways_to_beat_fast(&self) -> u64 {
+        let travels = |hold: u64| u128::from(hold) * u128::from(self.time - hold);
+        let half = self.time / 2;
+        if travels(half) <= u128::from(self.distance) {
+            return 0;
+        }
+        let (mut low, mut high) = (0, half);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if travels(mid) > u128::from(self.distance) {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        }
+        self.time - 2 * low + 1
+    }
+}
+
+#[derive(Debug)]
+pub struct Races {
+    pub records: Vec<Record>,
+    // part 2 reads each line as a single number with the spaces between the columns removed,
+    // so these keep the digits as written, leading zeros and all
+    pub time_digits: String,
+    pub distance_digits: String,
+}
+
+impl Races {
+    /// The one long race part 2 reads the input as.
+    pub fn joined(&self) -> Result<Record> {
+        let number = |digits: &str| {
+            digits.parse::<u64>().map_err(|_| {
+                Error::Unsolvable(format!("the joined number {digits} doesn't fit in u64"))
+            })
+        };
+        Ok(Record {
+            time: number(&self.time_digits)?,
+            distance: number(&self.distance_digits)?,
+        })
+    }
+}
+
+// a column's digits as written, checked to fit in u64
+fn column(input: &str) -> IResult<'_, &str> {
+    recognize(complete::u64)(input)
+}
+
+fn parse_races(input: &str) -> IResult<'_, Races> {
+    let (input, times) = tag("Time:")
+        .precedes(preceded(multispace1, separated_list1(multispace1, column)))
+        .terminated(line_ending)
+        .context("times")
+        .parse(input)?;
+    let (input, distances) = tag("Distance:")
+        .precedes(preceded(
+            multispace1,
+            verify(
+                separated_list1(multispace1, column),
+                |distances: &Vec<&str>| distances.len() == times.len(),
+            )
+            .context("one distance per time"),
+        ))
+        .terminated(line_ending)
+        .context("distances")
+        .parse(input)?;
+    let number = |digits: &&str| digits.parse().expect("columns should fit in u64");
+    let records = times
+        .iter()
+        .zip(&distances)
+        .map(|(time, distance)| Record {
+            time: number(time),
+            distance: number(distance),
+        })
+        .collect();
+    Ok((
+        input,
+        Races {
+            records,
+            time_digits: times.concat(),
+            distance_digits: distances.concat(),
+        },
+    ))
+}
+
+pub struct Day06;
+
+aoc_common::export_plugin!(Day06);
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 6;
+
+    type Parsed = Races;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        finish(input, parse_races)
+    }
+
+    fn part1(races: &Self::Parsed) -> Result<u64> {
+        Ok(races
+            .records
+            .iter()
+            .map(|record| record.count_ways_to_beat())
+            .product())
+    }
+
+    fn part2(races: &Self::Parsed) -> Result<u64> {
+        Ok(races.joined()?.count_ways_to_beat_fast())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::example_answer;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn part1_example_case() {
+        let parsed = Day06::parse_file("input/p1.example.txt").expect("example input should parse");
+        let result = Day06::part1(&parsed).expect("example should have an answer");
+        let expected =
+            example_answer("input/p1.example.txt", 1).expect("example should have a part 1 answer");
+        assert_eq!(result.to_string(), expected);
+    }
+
+    #[test]
+    fn part2_example_case() {
+        let parsed = Day06::parse_file("input/p1.example.txt").expect("example input should parse");
+        let result = Day06::part2(&parsed).expect("example should have an answer");
+        let expected =
+            example_answer("input/p1.example.txt", 2).expect("example should have a part 2 answer");
+        assert_eq!(result.to_string(), expected);
+    }
+
+    #[test]
+    fn missing_distance() {
+        let err =
+            Day06::parse("Time: 7 15 30\nDistance: 9 40\n").expect_err("a distance is missing");
+        assert!(err.to_string().starts_with("<input>:2:11: "), "{err}");
+        assert!(
+            err.to_string().contains("expected one distance per time"),
+            "{err}"
+        );
+    }
+
+    fn record() -> impl Strategy<Value = Record> {
+        // distances up to a little past the best possible, so some records can't be beaten
+        (1..500u64).prop_flat_map(|time| {
+            (0..=time * time / 4 + 2).prop_map(move |distance| Record { time, distance })
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn fast_count_matches_linear_scan(record in record()) {
+            prop_assert_eq!(record.count_ways_to_beat_fast(), record.count_ways_to_beat());
+        }
+    }
+
+    #[test]
+    fn joined_digits_keep_leading_zeros() {
+        let races = Day06::parse("Time: 7 05\nDistance: 9 40\n").expect("input should parse");
+        let joined = races.joined().expect("705 and 940 fit in u64");
+        assert_eq!((joined.time, joined.distance), (705, 940));
+    }
+
+    #[test]
+    fn joined_digits_past_u64_max() {
+        let races = Day06::parse("Time: 99999999999 99999999999\nDistance: 1 2\n")
+            .expect("each column fits in u64");
+        let err = Day06::part2(&races).expect_err("the joined time doesn't fit");
+        assert_eq!(
+            err.to_string(),
+            "the joined number 9999999999999999999999 doesn't fit in u64"
+        );
+    }
+}
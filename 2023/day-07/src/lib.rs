@@ -0,0 +1,378 @@
+use core::panic;
+use std::{cmp::Ordering, collections::HashMap};
+
+use aoc_common::{
+    parse::{finish, IResult},
+    Error, Explore, Result, Solution,
+};
+use nom::{
+    character::complete::{self, line_ending, one_of, space1},
+    multi::{count, separated_list1},
+    sequence::separated_pair,
+};
+use nom_supreme::ParserExt;
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy)]
+pub enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+/// A hand ready to be ranked, strongest type first and then card by card.
+#[derive(Debug, Eq, Clone, Copy)]
+pub struct Hand {
+    pub card_values: [u8; 5],
+    pub hand_type: HandType,
+    pub bid: usize,
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let hand_type_cmp = self.hand_type.cmp(&other.hand_type);
+        if !hand_type_cmp.is_eq() {
+            return hand_type_cmp;
+        }
+        // if hand type is equal, we must check the individual cards
+        for i in 0..self.card_values.len() {
+            let this_value_cmp = self.card_values[i].cmp(&other.card_values[i]);
+            if this_value_cmp.is_eq() {
+                continue;
+            }
+            return this_value_cmp;
+        }
+        Ordering::Equal
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Hand {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+// won't make it generic, for now
+#[derive(Debug)]
+struct BTreeNode {
+    val: Hand,
+    left: Option<Box<BTreeNode>>,
+    right: Option<Box<BTreeNode>>,
+}
+
+#[derive(Debug)]
+struct BTree {
+    root: Option<Box<BTreeNode>>,
+}
+
+impl From<BTreeNode> for Option<Box<BTreeNode>> {
+    fn from(value: BTreeNode) -> Self {
+        Some(Box::new(value))
+    }
+}
+
+impl BTreeNode {
+    fn new(v: Hand) -> Self {
+        BTreeNode {
+            val: v,
+            left: None,
+            right: None,
+        }
+    }
+}
+
+impl BTree {
+    fn new() -> Self {
+        BTree { root: None }
+    }
+
+    fn insert(&mut self, v: Hand) {
+        if self.root.is_none() {
+            self.root = BTreeNode::new(v).into();
+            return;
+        }
+        let mut q: Vec<&mut Box<BTreeNode>> = Vec::new();
+        let root = self.root.as_mut().unwrap();
+        q.push(root);
+        while let Some(node) = q.pop() {
+            if v > node.val {
+                let right = &mut node.right;
+                match right {
+                    Some(n) => {
+                        q.push(n);
+                    }
+                    None => {
+                        *right = BTreeNode::new(v).into();
+                    }
+                }
+            } else {
+                let left = &mut node.left;
+                match left {
+                    Some(n) => {
+                        q.push(n);
+                    }
+                    None => {
+                        *left = BTreeNode::new(v).into();
+                    }
+                }
+            }
+        }
+    }
+
+    fn inorder_rec(values: &mut Vec<Hand>, node: &BTreeNode) {
+        if let Some(ref left) = node.left {
+            BTree::inorder_rec(values, left);
+        }
+        values.push(node.val);
+        if let Some(ref right) = node.right {
+            BTree::inorder_rec(values, right);
+        }
+    }
+
+    fn inorder(&self) -> Vec<Hand> {
+        let mut results = Vec::new();
+        if self.root.is_none() {
+            return results;
+        }
+        if let Some(ref root) = self.root {
+            BTree::inorder_rec(&mut results, root);
+        }
+        results
+    }
+}
+
+impl HandType {
+    /// The best type a hand can make, where a card value of 1 is a joker.
+    pub fn check(card_values: &[u8; 5]) -> Self {
+        let mut map: HashMap<u8, u32> = HashMap::new();
+        let mut joker_count = 0;
+        card_values.iter().for_each(|v| {
+            if *v == 1u8 {
+                joker_count += 1;
+            } else {
+                *map.entry(*v).or_insert(0) += 1;
+            }
+        });
+        // special case for all jokers, we have 5 of a kind by default and can stop here
+        if joker_count == 5 {
+            return HandType::FiveOfAKind;
+        }
+        let mut max_entry: Option<(u8, u32)> = None;
+        map.iter().for_each(|(key, val)| {
+            if max_entry.is_none() {
+                max_entry = Some((*key, *val));
+                return;
+            }
+            let (_, max_value) = max_entry.unwrap();
+            if val > &max_value {
+                max_entry = Some((*key, *val));
+            }
+        });
+        let (key, val) = max_entry.expect("should have found a maximum");
+        map.insert(key, val + joker_count);
+        match map.len() {
+            1 => HandType::FiveOfAKind,
+            2 => match map.into_values().max() {
+                Some(4) => HandType::FourOfAKind,
+                Some(3) => HandType::FullHouse,
+                _ => panic!("impossible"),
+            },
+            3 => match map.into_values().max() {
+                Some(3) => HandType::ThreeOfAKind,
+                Some(2) => HandType::TwoPair,
+                _ => panic!("impossible"),
+            },
+            4 => HandType::OnePair,
+            5 => HandType::HighCard,
+            _ => panic!("impossible"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Play {
+    pub cards: [char; 5],
+    pub bid: usize,
+}
+
+impl Play {
+    pub fn card_values(&self, jokers: bool) -> [u8; 5] {
+        // assumes that the digits 0 and 1 cannot show in the input, so 1 is free for the joker
+        self.cards.map(|v| match v.to_digit(10) {
+            Some(digit) => digit.try_into().expect("should not be bigger than u8"),
+            None => match v {
+                'T' => 10,
+                'J' if jokers => 1,
+                'J' => 11,
+                'Q' => 12,
+                'K' => 13,
+                'A' => 14,
+                _ => unreachable!("cards are checked when parsing"),
+            },
+        })
+    }
+
+    pub fn hand(&self, jokers: bool) -> Hand {
+        let card_values = self.card_values(jokers);
+        let hand_type = HandType::check(&card_values);
+        Hand {
+            card_values,
+            hand_type,
+            bid: self.bid,
+        }
+    }
+}
+
+/// Each bid times its hand's rank, counting from the weakest hand as 1.
+pub fn total_winnings(plays: &[Play], jokers: bool) -> usize {
+    let mut tree = BTree::new();
+    plays.iter().for_each(|play| tree.insert(play.hand(jokers)));
+    BTree::inorder(&tree)
+        .into_iter()
+        .enumerate()
+        .map(|(i, hand)| hand.bid * (i + 1))
+        .sum::<usize>()
+}
+
+fn parse_game(input: &str) -> IResult<'_, Vec<Play>> {
+    let cards = count(one_of("23456789TJQKA"), 5).context("hand");
+    let (input, output) = separated_list1(
+        line_ending,
+        separated_pair(cards, space1, complete::u32.context("bid")),
+    )(input)?;
+    let plays = output
+        .into_iter()
+        .map(|(cards, bid): (Vec<char>, u32)| Play {
+            cards: cards
+                .try_into()
+                .expect("should have exactly five card values"),
+            bid: bid.try_into().expect("should be able to convert to usize"),
+        })
+        .collect();
+    Ok((input, plays))
+}
+
+pub struct Day07;
+
+aoc_common::export_plugin!(Day07);
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 7;
+
+    type Parsed = Vec<Play>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        finish(input, parse_game)
+    }
+
+    fn part1(plays: &Self::Parsed) -> Result<usize> {
+        Ok(total_winnings(plays, false))
+    }
+
+    fn part2(plays: &Self::Parsed) -> Result<usize> {
+        Ok(total_winnings(plays, true))
+    }
+}
+
+impl Explore for Day07 {
+    const COMMANDS: &'static [(&'static str, &'static str)] = &[(
+        "hand <cards>",
+        "a hand's type with and without jokers, and its rank if it was dealt",
+    )];
+
+    fn explore(plays: &Vec<Play>, command: &str, arg: &str) -> Option<Result<String>> {
+        if command != "hand" {
+            return None;
+        }
+        let cards = arg.chars().collect::<Vec<_>>();
+        let cards = match <[char; 5]>::try_from(cards) {
+            Ok(cards) if cards.iter().all(|card| "23456789TJQKA".contains(*card)) => cards,
+            _ => {
+                return Some(Err(Error::InvalidArgument(format!(
+                    "{arg:?} isn't five of 23456789TJQKA"
+                ))))
+            }
+        };
+        let dealt = plays.iter().find(|play| play.cards == cards);
+        let play = Play {
+            cards,
+            bid: dealt.map_or(0, |play| play.bid),
+        };
+        let lines = [("without jokers", false), ("with jokers", true)].map(|(name, jokers)| {
+            let hand = play.hand(jokers);
+            let mut line = format!("{name}: {:?}", hand.hand_type);
+            if dealt.is_some() {
+                let rank = plays
+                    .iter()
+                    .filter(|other| other.hand(jokers) < hand)
+                    .count()
+                    + 1;
+                line += &format!(", rank {rank} of {}", plays.len());
+            }
+            line
+        });
+        let bid = match dealt {
+            Some(play) => format!("dealt with bid {}", play.bid),
+            None => "not dealt".to_string(),
+        };
+        Some(Ok(format!("{arg}: {bid}\n{}", lines.join("\n"))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::example_answer;
+
+    use super::*;
+
+    #[test]
+    fn part1_example_case() {
+        let parsed = Day07::parse_file("input/p1.example.txt").expect("example input should parse");
+        let result = Day07::part1(&parsed).expect("example should have an answer");
+        let expected =
+            example_answer("input/p1.example.txt", 1).expect("example should have a part 1 answer");
+        assert_eq!(result.to_string(), expected);
+    }
+
+    #[test]
+    fn part2_example_case() {
+        let parsed = Day07::parse_file("input/p1.example.txt").expect("example input should parse");
+        let result = Day07::part2(&parsed).expect("example should have an answer");
+        let expected =
+            example_answer("input/p1.example.txt", 2).expect("example should have a part 2 answer");
+        assert_eq!(result.to_string(), expected);
+    }
+
+    #[test]
+    fn hand_command_ranks_dealt_hands() {
+        let parsed = Day07::parse_file("input/p1.example.txt").expect("example input should parse");
+        let hand = Day07::explore(&parsed, "hand", "KTJJT").expect("day 7 should have hand");
+        assert_eq!(
+            hand.expect("KTJJT is a hand"),
+            "KTJJT: dealt with bid 220\n\
+             without jokers: TwoPair, rank 2 of 5\n\
+             with jokers: FourOfAKind, rank 5 of 5"
+        );
+        let hand = Day07::explore(&parsed, "hand", "AAAAA").expect("day 7 should have hand");
+        assert_eq!(
+            hand.expect("AAAAA is a hand"),
+            "AAAAA: not dealt\nwithout jokers: FiveOfAKind\nwith jokers: FiveOfAKind"
+        );
+        assert!(matches!(
+            Day07::explore(&parsed, "hand", "32T3"),
+            Some(Err(Error::InvalidArgument(_)))
+        ));
+    }
+}
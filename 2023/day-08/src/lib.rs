@@ -0,0 +1,329 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::{
+    math,
+    parse::{finish, IResult},
+    Error, Explore, Result, Solution,
+};
+use nom::{
+    bytes::complete::{is_a, take_until},
+    character::complete::{line_ending, multispace1},
+    multi::fold_many1,
+    sequence::{delimited, separated_pair, terminated},
+};
+use nom_supreme::{tag::complete::tag, ParserExt};
+
+#[derive(Debug)]
+pub struct Map {
+    pub directions: Vec<char>,
+    // each node's left and right neighbours
+    pub nodes: HashMap<String, (String, String)>,
+}
+
+impl Map {
+    pub fn step<'a>(&'a self, node: &str, direction: char) -> Result<&'a str> {
+        let (l, r) = self
+            .nodes
+            .get(node)
+            .ok_or_else(|| Error::Unsolvable(format!("map has no node {node:?}")))?;
+        match direction {
+            'L' => Ok(l),
+            'R' => Ok(r),
+            c => unreachable!("directions are checked when parsing, got {c}"),
+        }
+    }
+}
+
+fn node(input: &str) -> IResult<'_, (&str, (&str, &str))> {
+    let (input, output) = separated_pair(
+        take_until(" "),
+        tag(" = "),
+        delimited(
+            tag("("),
+            separated_pair(take_until(","), tag(", "), take_until(")")),
+            tag(")"),
+        ),
+    )(input)?;
+    Ok((input, output))
+}
+
+fn nodes(input: &str) -> IResult<'_, HashMap<String, (String, String)>> {
+    fold_many1(
+        terminated(node.context("node"), line_ending),
+        HashMap::new,
+        |mut map: HashMap<String, (String, String)>, (node, (l, r))| {
+            map.insert(node.to_string(), (l.to_string(), r.to_string()));
+            map
+        },
+    )(input)
+}
+
+fn parse_map(input: &str) -> IResult<'_, Map> {
+    let (input, (directions, nodes)) =
+        separated_pair(is_a("LR").context("directions"), multispace1, nodes)(input)?;
+    Ok((
+        input,
+        Map {
+            directions: directions.chars().collect(),
+            nodes,
+        },
+    ))
+}
+
+pub struct Day08;
+
+aoc_common::export_plugin!(Day08);
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 8;
+
+    type Parsed = Map;
+    type Answer1 = u32;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        finish(input, parse_map)
+    }
+
+    fn part1(map: &Self::Parsed) -> Result<u32> {
+        let directions = &map.directions;
+        let mut current_node = "AAA";
+        let mut steps = 0;
+        let mut seen = HashSet::new();
+        while current_node != "ZZZ" {
+            // back in a state it's been in before, the walk just repeats without reaching ZZZ
+            if !seen.insert((current_node, steps % directions.len())) {
+                return Err(Error::Unsolvable("AAA never leads to ZZZ".to_string()));
+            }
+            let direction = directions[steps % directions.len()];
+            current_node = map.step(current_node, direction)?;
+            steps += 1;
+        }
+        Ok(steps.try_into().expect("should fit in u32"))
+    }
+
+    fn part2(map: &Self::Parsed) -> Result<i64> {
+        let ghosts = map
+            .nodes
+            .keys()
+            .filter(|k| k.ends_with("A"))
+            .map(|start| Ghost::walk(map, start))
+            .collect::<Result<Vec<_>>>()?;
+        let steps = |steps: usize| i64::try_from(steps).expect("steps should fit in i64");
+        // until the last ghost starts going round its loop, any step with every ghost on a Z
+        // node is one of that ghost's arrivals before its loop, and it beats any step after
+        let early = ghosts
+            .iter()
+            .flat_map(|ghost| &ghost.before)
+            .filter(|&&arrival| ghosts.iter().all(|ghost| ghost.on_z(arrival)))
+            .min();
+        if let Some(&arrival) = early {
+            return Ok(steps(arrival));
+        }
+        // after that every ghost is in its loop, so the answer picks one of each loop's
+        // arrivals. Going ghost by ghost, each pick so far is a step they could all meet at, mod
+        // the lcm of their periods, with the latest arrival picked, and picks that can never
+        // line up are dropped before they multiply
+        let mut picks = vec![(0, 1, 0)];
+        for ghost in &ghosts {
+            picks = picks
+                .iter()
+                .flat_map(|&(x, m, latest)| {
+                    ghost.in_loop.iter().filter_map(move |&arrival| {
+                        let arrival = steps(arrival);
+                        let (x, m) = math::crt(&[(x, m), (arrival, steps(ghost.period))])?;
+                        Some((x, m, latest.max(arrival)))
+                    })
+                })
+                .collect();
+            picks.sort_unstable();
+            picks.dedup();
+        }
+        picks
+            .into_iter()
+            // the first of x, x + m, x + 2m... that every ghost has arrived by, where being on
+            // or after `latest` is a cycle that hits on every step from there
+            .filter_map(|(x, m, latest)| math::cycles_align(&[(x, m), (latest, 1)]))
+            .min()
+            .ok_or_else(|| Error::Unsolvable("the ghosts are never all on Z nodes".to_string()))
+    }
+}
+
+// the steps one ghost is on a Z node at: some before it starts going round a loop, then the
+// same places in the loop every time round
+struct Ghost {
+    before: Vec<usize>,
+    loop_start: usize,
+    period: usize,
+    in_loop: Vec<usize>,
+}
+
+impl Ghost {
+    fn walk(map: &Map, start: &str) -> Result<Ghost> {
+        let directions = &map.directions;
+        let mut current_node = start;
+        let mut steps = 0;
+        let mut arrivals = Vec::new();
+        // the step each (node, place in the directions) state was first reached at
+        let mut seen = HashMap::new();
+        loop {
+            let state = (current_node, steps % directions.len());
+            if let Some(loop_start) = seen.insert(state, steps) {
+                if arrivals.is_empty() {
+                    return Err(Error::Unsolvable(format!(
+                        "the ghost from {start} never reaches a Z node"
+                    )));
+                }
+                // an arrival at this step is the one at `loop_start` again
+                let (before, in_loop) = arrivals
+                    .into_iter()
+                    .filter(|&arrival| arrival < steps)
+                    .partition(|&arrival| arrival < loop_start);
+                return Ok(Ghost {
+                    before,
+                    loop_start,
+                    period: steps - loop_start,
+                    in_loop,
+                });
+            }
+            let direction = directions[steps % directions.len()];
+            current_node = map.step(current_node, direction)?;
+            steps += 1;
+            if current_node.ends_with("Z") {
+                arrivals.push(steps);
+            }
+        }
+    }
+
+    fn on_z(&self, steps: usize) -> bool {
+        if steps < self.loop_start {
+            self.before.contains(&steps)
+        } else {
+            let place = self.loop_start + (steps - self.loop_start) % self.period;
+            self.in_loop.contains(&place)
+        }
+    }
+}
+
+impl Explore for Day08 {
+    const COMMANDS: &'static [(&'static str, &'static str)] =
+        &[("node <name>", "where a node's left and right turns lead")];
+
+    fn explore(map: &Map, command: &str, arg: &str) -> Option<Result<String>> {
+        if command != "node" {
+            return None;
+        }
+        let Some((l, r)) = map.nodes.get(arg) else {
+            return Some(Err(Error::InvalidArgument(format!(
+                "map has no node {arg:?}"
+            ))));
+        };
+        Some(Ok(format!("{arg} = ({l}, {r})")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::example_answer;
+
+    use super::*;
+
+    #[test]
+    fn part1_example_case() {
+        let parsed = Day08::parse_file("input/p1.example.txt").expect("example input should parse");
+        let result = Day08::part1(&parsed).expect("example should have an answer");
+        let expected =
+            example_answer("input/p1.example.txt", 1).expect("example should have a part 1 answer");
+        assert_eq!(result.to_string(), expected);
+    }
+
+    #[test]
+    fn part1_repeated_case() {
+        let parsed =
+            Day08::parse_file("input/p1.example2.txt").expect("example input should parse");
+        let result = Day08::part1(&parsed).expect("example should have an answer");
+        let expected = example_answer("input/p1.example2.txt", 1)
+            .expect("example should have a part 1 answer");
+        assert_eq!(result.to_string(), expected);
+    }
+
+    #[test]
+    fn part2_example_case() {
+        let parsed = Day08::parse_file("input/p2.example.txt").expect("example input should parse");
+        let result = Day08::part2(&parsed).expect("example should have an answer");
+        let expected =
+            example_answer("input/p2.example.txt", 2).expect("example should have a part 2 answer");
+        assert_eq!(result.to_string(), expected);
+    }
+
+    #[test]
+    fn missing_node() {
+        let map = Day08::parse("L\n\nAAA = (BBB, BBB)\n").expect("input should parse");
+        let err = Day08::part1(&map).expect_err("BBB is never defined");
+        assert_eq!(err.to_string(), "map has no node \"BBB\"");
+    }
+
+    #[test]
+    fn zzz_never_reached() {
+        let map = Day08::parse("L\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)\n")
+            .expect("input should parse");
+        let err = Day08::part1(&map).expect_err("AAA and BBB only lead to each other");
+        assert_eq!(err.to_string(), "AAA never leads to ZZZ");
+    }
+
+    #[test]
+    fn ghost_that_never_arrives() {
+        let map =
+            Day08::parse("L\n\n11A = (11B, 11B)\n11B = (11A, 11A)\n").expect("input should parse");
+        let err = Day08::part2(&map).expect_err("11A never reaches a Z node");
+        assert_eq!(err.to_string(), "the ghost from 11A never reaches a Z node");
+        let map = Day08::parse(
+            "L\n\n11A = (11Z, 11Z)\n11Z = (11B, 11B)\n11B = (11B, 11B)\n\
+             21A = (21Z, 21Z)\n21Z = (21Z, 21Z)\n",
+        )
+        .expect("input should parse");
+        // both are on Z nodes after one step, which 11A never is again
+        assert_eq!(Day08::part2(&map).expect("step 1 works"), 1);
+        let map = Day08::parse(
+            "L\n\n11A = (11B, 11B)\n11B = (11Z, 11Z)\n11Z = (11C, 11C)\n11C = (11C, 11C)\n\
+             21A = (21Z, 21Z)\n21Z = (21B, 21B)\n21B = (21Z, 21Z)\n",
+        )
+        .expect("input should parse");
+        let err = Day08::part2(&map).expect_err("11A is on 11Z at step 2, 21A at odd steps");
+        assert_eq!(err.to_string(), "the ghosts are never all on Z nodes");
+    }
+
+    #[test]
+    fn ghost_with_one_z_in_its_loop() {
+        let map = Day08::parse("LR\n\n11A = (11Z, 11B)\n11Z = (11B, 11A)\n11B = (11A, 11A)\n")
+            .expect("input should parse");
+        // 11A -L-> 11Z -R-> 11A -L-> 11Z: at 11Z on steps 1, 3, 5...
+        assert_eq!(Day08::part2(&map).expect("the ghost loops through 11Z"), 1);
+    }
+
+    #[test]
+    fn node_command_shows_turns() {
+        let parsed = Day08::parse_file("input/p1.example.txt").expect("example input should parse");
+        let node = Day08::explore(&parsed, "node", "AAA").expect("day 8 should have node");
+        assert_eq!(node.expect("AAA is a node"), "AAA = (BBB, CCC)");
+        let node = Day08::explore(&parsed, "node", "QQQ").expect("day 8 should have node");
+        let err = node.expect_err("QQQ isn't a node");
+        assert!(matches!(err, Error::InvalidArgument(_)));
+        assert_eq!(err.to_string(), "map has no node \"QQQ\"");
+    }
+
+    #[test]
+    fn ghosts_with_z_nodes_before_and_several_in_their_loops() {
+        // 11A is on a Z node at steps 1, 3, 6, 9, 12... and 21A at 5, 10, 15...
+        let map = Day08::parse(
+            "L\n\n\
+             11A = (12Z, 12Z)\n12Z = (11B, 11B)\n11B = (13Z, 13Z)\n13Z = (11C, 11C)\n\
+             11C = (11D, 11D)\n11D = (13Z, 13Z)\n\
+             21A = (21B, 21B)\n21B = (21C, 21C)\n21C = (21D, 21D)\n21D = (21E, 21E)\n\
+             21E = (21Z, 21Z)\n21Z = (21B, 21B)\n",
+        )
+        .expect("input should parse");
+        assert_eq!(Day08::part2(&map).expect("the ghosts meet at step 15"), 15);
+    }
+}
@@ -0,0 +1,122 @@
+use aoc_common::{
+    parse::{finish, IResult},
+    Error, Result, Solution,
+};
+use nom::{
+    character::complete::{self, line_ending, space1},
+    multi::separated_list1,
+};
+
+fn parse_report(input: &str) -> IResult<'_, Vec<Vec<i64>>> {
+    let (input, data) =
+        separated_list1(line_ending, separated_list1(space1, complete::i64))(input)?;
+    Ok((input, data))
+}
+
+// a sequence whose differences run out before they're all zero has no pattern to follow
+fn never_settles(data: &[i64]) -> Error {
+    Error::Unsolvable(format!("the differences of {data:?} never settle to zeros"))
+}
+
+/// The next value in the sequence, going by its differences.
+pub fn extrapolate(data: &[i64]) -> Result<i64> {
+    let mut nums = data.to_vec();
+    let mut end_nums: Vec<i64> = vec![*nums.last().ok_or_else(|| never_settles(data))?];
+    loop {
+        if nums.iter().all(|x| x == &0) {
+            break;
+        }
+        nums = (0..(nums.len() - 1))
+            .map(|i| {
+                let first = nums[i];
+                let second = nums[i + 1];
+                second - first
+            })
+            .collect::<Vec<i64>>();
+        end_nums.push(*nums.last().ok_or_else(|| never_settles(data))?);
+    }
+    Ok(end_nums.iter().sum())
+}
+
+/// The value before the first one, going by its differences.
+pub fn extrapolate_backwards(data: &[i64]) -> Result<i64> {
+    let mut nums = data.to_vec();
+    let mut first_nums: Vec<i64> = vec![*nums.first().ok_or_else(|| never_settles(data))?];
+    loop {
+        if nums.iter().all(|x| x == &0) {
+            break;
+        }
+        nums = (1..(nums.len()))
+            .rev()
+            .map(|i| {
+                let first = nums[i];
+                let second = nums[i - 1];
+                first - second
+            })
+            .rev()
+            .collect::<Vec<i64>>();
+        first_nums.push(*nums.first().ok_or_else(|| never_settles(data))?);
+    }
+    Ok(first_nums.iter().rev().fold(0, |acc: i64, x| x - acc))
+}
+
+pub struct Day09;
+
+aoc_common::export_plugin!(Day09);
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 9;
+
+    type Parsed = Vec<Vec<i64>>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        finish(input, parse_report)
+    }
+
+    fn part1(report: &Self::Parsed) -> Result<i64> {
+        report.iter().map(|data| extrapolate(data)).sum()
+    }
+
+    fn part2(report: &Self::Parsed) -> Result<i64> {
+        report.iter().map(|data| extrapolate_backwards(data)).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::example_answer;
+
+    use super::*;
+
+    #[test]
+    fn part1_example_case() {
+        let parsed = Day09::parse_file("input/p1.example.txt").expect("example input should parse");
+        let result = Day09::part1(&parsed).expect("example should have an answer");
+        let expected =
+            example_answer("input/p1.example.txt", 1).expect("example should have a part 1 answer");
+        assert_eq!(result.to_string(), expected);
+    }
+
+    #[test]
+    fn part2_example_case() {
+        let parsed = Day09::parse_file("input/p1.example.txt").expect("example input should parse");
+        let result = Day09::part2(&parsed).expect("example should have an answer");
+        let expected =
+            example_answer("input/p1.example.txt", 2).expect("example should have a part 2 answer");
+        assert_eq!(result.to_string(), expected);
+    }
+
+    #[test]
+    fn differences_that_never_settle() {
+        let report = Day09::parse("1 2 4\n").expect("input should parse");
+        let err = Day09::part1(&report).expect_err("1 2 4 has no pattern");
+        assert_eq!(
+            err.to_string(),
+            "the differences of [1, 2, 4] never settle to zeros"
+        );
+        Day09::part2(&report).expect_err("1 2 4 has no pattern");
+    }
+}
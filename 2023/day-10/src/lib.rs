@@ -0,0 +1,422 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::{
+    search, Animate, Canvas, Cell, Colour, Direction, Error, Frame, Grid, ParseError, Player, Pos,
+    Render, Result, Solution,
+};
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum PipeType {
+    Vertical,
+    Horizontal,
+    NorthEast,
+    NorthWest,
+    SouthWest,
+    SouthEast,
+}
+
+impl PipeType {
+    const ALL: [PipeType; 6] = [
+        PipeType::Vertical,
+        PipeType::Horizontal,
+        PipeType::NorthEast,
+        PipeType::NorthWest,
+        PipeType::SouthWest,
+        PipeType::SouthEast,
+    ];
+
+    pub fn connects(self, direction: Direction) -> bool {
+        use Direction::*;
+        let ends = match self {
+            PipeType::Vertical => [North, South],
+            PipeType::Horizontal => [East, West],
+            PipeType::NorthEast => [North, East],
+            PipeType::NorthWest => [North, West],
+            PipeType::SouthWest => [South, West],
+            PipeType::SouthEast => [South, East],
+        };
+        ends.contains(&direction)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TileType {
+    PipeType(PipeType),
+    Ground,
+    Start,
+}
+
+impl TileType {
+    // box drawing characters, which join up in a terminal where the input's letters don't
+    fn symbol(self) -> char {
+        match self {
+            TileType::PipeType(PipeType::Vertical) => '│',
+            TileType::PipeType(PipeType::Horizontal) => '─',
+            TileType::PipeType(PipeType::NorthEast) => '└',
+            TileType::PipeType(PipeType::NorthWest) => '┘',
+            TileType::PipeType(PipeType::SouthWest) => '┐',
+            TileType::PipeType(PipeType::SouthEast) => '┌',
+            TileType::Ground => '.',
+            TileType::Start => 'S',
+        }
+    }
+
+    // the start could be hiding any pipe, so it might connect anywhere
+    pub fn connects(self, direction: Direction) -> bool {
+        match self {
+            TileType::PipeType(pipe) => pipe.connects(direction),
+            TileType::Ground => false,
+            TileType::Start => true,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Field {
+    tiles: Grid<TileType>,
+}
+
+impl Field {
+    pub fn tiles(&self) -> &Grid<TileType> {
+        &self.tiles
+    }
+
+    pub fn get_start_tile(&self) -> Pos {
+        self.tiles
+            .position(|tile| *tile == TileType::Start)
+            .expect("start tile is checked for when parsing")
+    }
+
+    /// The neighbours of `pos` connected to it by both tiles' pipes, with the direction of each.
+    pub fn connections(&self, pos: Pos) -> impl Iterator<Item = (Direction, Pos)> + '_ {
+        let tile = self.tiles[pos];
+        Direction::ALL.into_iter().filter_map(move |direction| {
+            let next = self.tiles.step(pos, direction)?;
+            // only a pipe can lead back, the start is where the loop began
+            let next_allows_current = matches!(
+                self.tiles[next],
+                TileType::PipeType(pipe) if pipe.connects(direction.opposite())
+            );
+            (tile.connects(direction) && next_allows_current).then_some((direction, next))
+        })
+    }
+
+    /// The pipe hidden under the start tile, going by which neighbours connect to it.
+    pub fn start_pipe(&self) -> Result<PipeType> {
+        let directions = self
+            .connections(self.get_start_tile())
+            .map(|(direction, _)| direction)
+            .collect::<Vec<_>>();
+        let candidates = PipeType::ALL
+            .into_iter()
+            .filter(|pipe| directions.iter().all(|direction| pipe.connects(*direction)))
+            .collect::<Vec<_>>();
+        match candidates[..] {
+            [start_pipe] => Ok(start_pipe),
+            _ => Err(Error::Unsolvable(
+                "start tile should connect to exactly two pipes".to_string(),
+            )),
+        }
+    }
+
+    /// How far each tile of the loop is from the start, and the pipe under the start.
+    pub fn find_loop(&self) -> Result<(HashMap<Pos, usize>, PipeType)> {
+        let start_pipe = self.start_pipe()?;
+        let distances = search::bfs(self.get_start_tile(), |pos| {
+            self.connections(*pos).map(|(_, next)| next)
+        });
+        Ok((distances, start_pipe))
+    }
+
+    // a copy with only the loop's pipes left, and the start replaced by the pipe under it
+    fn without_junk(&self) -> Result<Field> {
+        let (loop_pipes, start_pipe) = self.find_loop()?;
+        let mut field = self.clone();
+        field.tiles[self.get_start_tile()] = TileType::PipeType(start_pipe);
+        for pos in field.tiles.positions() {
+            if !loop_pipes.contains_key(&pos) {
+                field.tiles[pos] = TileType::Ground;
+            }
+        }
+        Ok(field)
+    }
+
+    /// The tiles inside the loop, in reading order.
+    pub fn enclosed_tiles(&self) -> Result<Vec<Pos>> {
+        // point in polygon raycasting algorithm:
+        // if the number of intersections with the border
+        // is even then the point lies outside of the polygon
+        // else the point lies within the polygon
+        // note that for the following case, the border is not intersected/crossed:
+        // .L-----J.
+        // we are simply moving along the border and not crossing it
+        // (from the right dot to the left, we do not enter the polygon), unlike:
+        // .F-----J.
+        // in which the border must be crossed (i.e. we will start inside the polygon
+        // and leave by the time we reach the left dot)
+        let field = self.without_junk()?;
+        let mut inside_points = Vec::new();
+        for (i, row) in field.tiles.rows().enumerate() {
+            for (j, &tile) in row.iter().enumerate() {
+                if tile == TileType::Ground {
+                    let mut intersections = 0;
+                    let mut last_bend: Option<TileType> = None;
+                    row[..j].iter().rev().for_each(|&k_tile| {
+                        if k_tile == TileType::PipeType(PipeType::Vertical) {
+                            intersections += 1;
+                        }
+                        if k_tile == TileType::PipeType(PipeType::NorthEast) {
+                            if let Some(bend) = last_bend {
+                                if bend == TileType::PipeType(PipeType::SouthWest) {
+                                    intersections += 1;
+                                    last_bend = None;
+                                }
+                                if bend == TileType::PipeType(PipeType::NorthWest) {
+                                    last_bend = None;
+                                }
+                            }
+                        }
+                        if k_tile == TileType::PipeType(PipeType::SouthEast) {
+                            if let Some(bend) = last_bend {
+                                if bend == TileType::PipeType(PipeType::NorthWest) {
+                                    intersections += 1;
+                                    last_bend = None;
+                                }
+                                if bend == TileType::PipeType(PipeType::SouthWest) {
+                                    last_bend = None;
+                                }
+                            }
+                        }
+                        if k_tile == TileType::PipeType(PipeType::SouthWest) {
+                            last_bend = Some(TileType::PipeType(PipeType::SouthWest));
+                        }
+                        if k_tile == TileType::PipeType(PipeType::NorthWest) {
+                            last_bend = Some(TileType::PipeType(PipeType::NorthWest));
+                        }
+                    });
+                    if intersections % 2 == 1 {
+                        inside_points.push((i, j));
+                    }
+                }
+            }
+        }
+        Ok(inside_points)
+    }
+
+    fn frame(&self, caption: String, mut colour: impl FnMut(Pos) -> Colour) -> Frame {
+        let mut cells = self
+            .tiles
+            .map(|tile| Cell::new(tile.symbol(), Colour::GREY));
+        for pos in cells.positions() {
+            cells[pos].colour = colour(pos);
+        }
+        Frame { cells, caption }
+    }
+}
+
+fn parse_field(input: &str) -> Result<Field> {
+    let tiles = Grid::parse(input, "a pipe, '.' or 'S'", |c| {
+        let tile = match c {
+            '.' => TileType::Ground,
+            'S' => TileType::Start,
+            '|' => TileType::PipeType(PipeType::Vertical),
+            '-' => TileType::PipeType(PipeType::Horizontal),
+            'L' => TileType::PipeType(PipeType::NorthEast),
+            'J' => TileType::PipeType(PipeType::NorthWest),
+            '7' => TileType::PipeType(PipeType::SouthWest),
+            'F' => TileType::PipeType(PipeType::SouthEast),
+            _ => return None,
+        };
+        Some(tile)
+    })?;
+    if tiles.position(|tile| *tile == TileType::Start).is_none() {
+        return Err(ParseError::at(input, &input[input.len()..], "a start tile 'S'").into());
+    }
+    Ok(Field { tiles })
+}
+
+pub struct Day10;
+
+aoc_common::export_plugin!(Day10);
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 10;
+
+    type Parsed = Field;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse_field(input)
+    }
+
+    fn part1(field: &Self::Parsed) -> Result<usize> {
+        let (distances, _) = field.find_loop()?;
+        Ok(distances.into_values().max().unwrap_or_default())
+    }
+
+    fn part2(field: &Self::Parsed) -> Result<usize> {
+        Ok(field.enclosed_tiles()?.len())
+    }
+}
+
+// the loop in white with its route traced over it from the yellow start, the tiles it encloses
+// in green, and the junk it doesn't use in grey
+impl Render for Day10 {
+    fn render(field: &Self::Parsed) -> Result<Canvas> {
+        let (loop_pipes, _) = field.find_loop()?;
+        let enclosed = field.enclosed_tiles()?.into_iter().collect::<HashSet<_>>();
+        let start = field.get_start_tile();
+        // each pipe of the loop has only two connections, so a depth-first walk goes round it
+        let mut route = search::dfs(start, |pos| {
+            field
+                .connections(*pos)
+                .map(|(_, next)| next)
+                .collect::<Vec<_>>()
+        });
+        route.push(start);
+        let mut canvas = Canvas::new(&field.tiles, |pos, tile| {
+            if loop_pipes.contains_key(&pos) {
+                Colour::WHITE
+            } else if enclosed.contains(&pos) {
+                Colour::GREEN
+            } else if *tile == TileType::Ground {
+                Colour::BLACK
+            } else {
+                Colour::GREY
+            }
+        });
+        canvas.path(&route, Colour::RED);
+        canvas.highlight(start, Colour::YELLOW);
+        Ok(canvas)
+    }
+}
+
+// the search spreading round the loop a step at a time from the start, then the raycast deciding
+// row by row which of the tiles left over are inside the loop
+impl Animate for Day10 {
+    fn animate(field: &Self::Parsed, player: Player<'_>) -> Result<()> {
+        let (distances, _) = field.find_loop()?;
+        let furthest = distances.values().copied().max().unwrap_or_default();
+        for step in 0..=furthest {
+            let reached = distances.values().filter(|d| **d <= step).count();
+            let caption = format!("finding the loop: {step} steps out, {reached} pipes reached");
+            let frame = field.frame(caption, |pos| match distances.get(&pos) {
+                Some(d) if *d == step => Colour::YELLOW,
+                Some(d) if *d < step => Colour::WHITE,
+                _ => Colour::GREY,
+            });
+            if player(frame).is_break() {
+                return Ok(());
+            }
+        }
+
+        let cleared = field.without_junk()?;
+        let enclosed = field.enclosed_tiles()?.into_iter().collect::<HashSet<_>>();
+        let height = cleared.tiles.height();
+        for row in 0..height {
+            let so_far = enclosed.iter().filter(|(r, _)| *r <= row).count();
+            let caption = format!("raycasting row {} of {height}: {so_far} enclosed", row + 1);
+            let frame = cleared.frame(caption, |pos| {
+                if distances.contains_key(&pos) {
+                    Colour::WHITE
+                } else if pos.0 > row {
+                    Colour::GREY
+                } else if enclosed.contains(&pos) {
+                    Colour::GREEN
+                } else {
+                    Colour::BLUE
+                }
+            });
+            if player(frame).is_break() {
+                return Ok(());
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::ControlFlow;
+
+    use aoc_common::example_answer;
+
+    use super::*;
+
+    #[test]
+    fn part1_example_case() {
+        let parsed = Day10::parse_file("input/p1.example.txt").expect("example input should parse");
+        let result = Day10::part1(&parsed).expect("example should have an answer");
+        let expected =
+            example_answer("input/p1.example.txt", 1).expect("example should have a part 1 answer");
+        assert_eq!(result.to_string(), expected);
+    }
+
+    #[test]
+    fn part1_second_case() {
+        let parsed =
+            Day10::parse_file("input/p1.example2.txt").expect("example input should parse");
+        let result = Day10::part1(&parsed).expect("example should have an answer");
+        let expected = example_answer("input/p1.example2.txt", 1)
+            .expect("example should have a part 1 answer");
+        assert_eq!(result.to_string(), expected);
+    }
+
+    #[test]
+    fn part2_example_case() {
+        let parsed = Day10::parse_file("input/p2.example.txt").expect("example input should parse");
+        let result = Day10::part2(&parsed).expect("example should have an answer");
+        let expected =
+            example_answer("input/p2.example.txt", 2).expect("example should have a part 2 answer");
+        assert_eq!(result.to_string(), expected);
+    }
+
+    #[test]
+    fn renders_the_loop() {
+        let parsed = Day10::parse_file("input/p2.example.txt").expect("example input should parse");
+        let svg = Day10::render(&parsed)
+            .expect("example should render")
+            .to_svg();
+        // every tile, the start's outline and the route round the loop
+        assert_eq!(
+            svg.matches("<rect ").count(),
+            parsed.tiles.width() * parsed.tiles.height() + 1
+        );
+        assert_eq!(svg.matches("fill=\"#859900\"").count(), 4);
+        assert_eq!(svg.matches("<polyline ").count(), 1);
+    }
+
+    #[test]
+    fn animates_the_search_then_the_raycast() {
+        let parsed = Day10::parse_file("input/p2.example.txt").expect("example input should parse");
+        let mut frames = Vec::new();
+        Day10::animate(&parsed, &mut |frame| {
+            frames.push(frame);
+            ControlFlow::Continue(())
+        })
+        .expect("example should animate");
+        let furthest = Day10::part1(&parsed).expect("example should have an answer");
+        assert_eq!(frames.len(), furthest + 1 + parsed.tiles.height());
+        let last = frames.last().expect("there should be frames");
+        assert!(last.caption.ends_with(": 4 enclosed"), "{}", last.caption);
+
+        let mut shown = 0;
+        Day10::animate(&parsed, &mut |_| {
+            shown += 1;
+            ControlFlow::Break(())
+        })
+        .expect("example should animate");
+        assert_eq!(shown, 1);
+    }
+
+    #[test]
+    fn unknown_tile() {
+        let err = Day10::parse("S-7\n|x|\nL-J\n").expect_err("x is not a tile");
+        assert!(
+            err.to_string().starts_with("<input>:2:2: expected a pipe"),
+            "{err}"
+        );
+    }
+}
@@ -0,0 +1,193 @@
+use std::{
+    cmp::max,
+    cmp::min,
+    collections::{HashMap, HashSet},
+};
+
+use aoc_common::{Canvas, Colour, Grid, Pos, Render, Result, Solution};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DataPoint {
+    Nothing,
+    Galaxy(usize),
+}
+
+pub type Image = Grid<DataPoint>;
+
+fn parse_img(input: &str) -> Result<Image> {
+    let mut next_id = 1;
+    Grid::parse(input, "'.' or '#'", |c| match c {
+        '.' => Some(DataPoint::Nothing),
+        '#' => {
+            next_id += 1;
+            Some(DataPoint::Galaxy(next_id - 1))
+        }
+        _ => None,
+    })
+}
+
+fn expand_rows(img: &Image) -> Image {
+    let rows = img.rows().flat_map(|row| {
+        let copies = match row.iter().all(|c| *c == DataPoint::Nothing) {
+            true => 2,
+            false => 1,
+        };
+        std::iter::repeat_n(row.to_vec(), copies)
+    });
+    Grid::from_rows(rows)
+}
+
+/// Doubles every row and column without a galaxy.
+pub fn expand(img: &Image) -> Image {
+    let expanded_rows = expand_rows(img);
+    let transposed = expanded_rows.transpose();
+    let expanded_cols = expand_rows(&transposed);
+    expanded_cols.transpose()
+}
+
+pub fn get_galaxy_locations(img: &Image) -> HashMap<usize, Pos> {
+    img.iter()
+        .filter_map(|(pos, dp)| match dp {
+            DataPoint::Galaxy(id) => Some((*id, pos)),
+            DataPoint::Nothing => None,
+        })
+        .collect()
+}
+
+/// The sum of the distances between every pair of galaxies, with each empty row and column
+/// counting as `expansion_factor` of them.
+pub fn expanded_distance_sum(img: &Image, expansion_factor: usize) -> usize {
+    let galaxies = get_galaxy_locations(img);
+    // manhattan distance for each pair
+    let mut distance_sum = 0;
+    let galaxy_count = galaxies.len();
+    for i in 1..=galaxy_count {
+        for j in (i + 1)..=galaxy_count {
+            let (start_row, first_col) = galaxies.get(&i).expect("id should be found in map");
+            let (end_row, second_col) = galaxies.get(&j).expect("id should be found in map");
+            let mut y_distance = 0;
+            // we assign ids via traversing rows then cols so we know we will only move down the
+            // image from the lower id
+            // check whether a row would be expanded or if it is just a singular row
+            for row in (*start_row + 1)..=*end_row {
+                y_distance += match img.row(row).iter().all(|&dp| dp == DataPoint::Nothing) {
+                    true => expansion_factor,
+                    false => 1,
+                };
+            }
+            let start_col = min(first_col, second_col);
+            let end_col = max(first_col, second_col);
+            let mut x_distance = 0;
+            // check whether a column would be expanded or if it is just a singular column
+            for k in (*start_col + 1)..=*end_col {
+                let galaxy_found = img.column(k).any(|dp| matches!(dp, DataPoint::Galaxy(_)));
+                if galaxy_found {
+                    x_distance += 1;
+                } else {
+                    x_distance += expansion_factor;
+                }
+            }
+            distance_sum += y_distance + x_distance;
+        }
+    }
+    distance_sum
+}
+
+pub struct Day11;
+
+aoc_common::export_plugin!(Day11);
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 11;
+
+    type Parsed = Image;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse_img(input)
+    }
+
+    fn part1(img: &Self::Parsed) -> Result<usize> {
+        let img = expand(img);
+        let galaxies = get_galaxy_locations(&img);
+        // manhattan distance for each pair
+        let mut distance_sum = 0;
+        let galaxy_count = galaxies.len();
+        for i in 1..=galaxy_count {
+            for j in (i + 1)..=galaxy_count {
+                let first_pos = galaxies.get(&i).expect("id should be found in map");
+                let second_pos = galaxies.get(&j).expect("id should be found in map");
+                let distance =
+                    second_pos.0.abs_diff(first_pos.0) + second_pos.1.abs_diff(first_pos.1);
+                distance_sum += distance;
+            }
+        }
+        Ok(distance_sum)
+    }
+
+    fn part2(img: &Self::Parsed) -> Result<usize> {
+        Ok(expanded_distance_sum(img, 1000000))
+    }
+}
+
+// the image after part 1's expansion, with the galaxies in yellow and the empty rows and
+// columns, each doubled by the expansion, in blue
+impl Render for Day11 {
+    fn render(img: &Self::Parsed) -> Result<Canvas> {
+        let img = expand(img);
+        let empty_rows = (0..img.height())
+            .filter(|row| img.row(*row).iter().all(|dp| *dp == DataPoint::Nothing))
+            .collect::<HashSet<_>>();
+        let empty_columns = (0..img.width())
+            .filter(|column| img.column(*column).all(|dp| *dp == DataPoint::Nothing))
+            .collect::<HashSet<_>>();
+        Ok(Canvas::new(&img, |(row, column), dp| match dp {
+            DataPoint::Galaxy(_) => Colour::YELLOW,
+            DataPoint::Nothing if empty_rows.contains(&row) || empty_columns.contains(&column) => {
+                Colour::BLUE
+            }
+            DataPoint::Nothing => Colour::BLACK,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::example_answer;
+
+    use super::*;
+
+    #[test]
+    fn part1_example_case() {
+        let parsed = Day11::parse_file("input/p1.example.txt").expect("example input should parse");
+        let result = Day11::part1(&parsed).expect("example should have an answer");
+        let expected =
+            example_answer("input/p1.example.txt", 1).expect("example should have a part 1 answer");
+        assert_eq!(result.to_string(), expected);
+    }
+
+    #[test]
+    fn expand_ten() {
+        let parsed = Day11::parse_file("input/p1.example.txt").expect("example input should parse");
+        let result = expanded_distance_sum(&parsed, 10);
+        assert_eq!(result, 1030);
+    }
+
+    #[test]
+    fn expand_hundred() {
+        let parsed = Day11::parse_file("input/p1.example.txt").expect("example input should parse");
+        let result = expanded_distance_sum(&parsed, 100);
+        assert_eq!(result, 8410);
+    }
+
+    #[test]
+    fn renders_the_expansion() {
+        let parsed = Day11::parse_file("input/p1.example.txt").expect("example input should parse");
+        let canvas = Day11::render(&parsed).expect("example should render");
+        // 10x10 grows by two empty rows and three empty columns, 8 pixels a cell
+        assert_eq!(canvas.size(), (13 * 8, 12 * 8));
+        assert_eq!(canvas.to_svg().matches("fill=\"#b58900\"").count(), 9);
+    }
+}
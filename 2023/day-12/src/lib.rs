@@ -0,0 +1,319 @@
+use std::{collections::HashMap, fmt};
+
+use aoc_common::{
+    parse::{finish, IResult},
+    Result, Solution,
+};
+use itertools::{repeat_n, Itertools};
+use nom::{
+    bytes::complete::is_a,
+    character::complete::{self, line_ending, space1},
+    multi::separated_list1,
+    sequence::separated_pair,
+};
+use nom_supreme::{tag::complete::tag, ParserExt};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SpringState {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+#[derive(Debug)]
+pub struct SpringRow {
+    pub springs: Vec<SpringState>,
+    // the lengths of the runs of damaged springs, in order
+    pub damaged_count: Vec<u64>,
+}
+
+impl fmt::Display for SpringRow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let springs = self
+            .springs
+            .iter()
+            .map(|state| match state {
+                SpringState::Unknown => '?',
+                SpringState::Damaged => '#',
+                SpringState::Operational => '.',
+            })
+            .join(",");
+        let damaged_count = self
+            .damaged_count
+            .iter()
+            .map(|num| num.to_string())
+            .join(",");
+        write!(f, "{springs}:{damaged_count}")
+    }
+}
+
+impl SpringRow {
+    /// Every way of filling in the unknown springs, whether or not it fits the counts.
+    pub fn generate_possible_completions(&self) -> Vec<Vec<SpringState>> {
+        // 1. get number of unknowns
+        let unknowns = self
+            .springs
+            .iter()
+            .filter(|state| **state == SpringState::Unknown)
+            .count();
+        // the product of no lists is empty rather than one empty permutation, so a row
+        // without unknowns is its own only completion
+        if unknowns == 0 {
+            return vec![self.springs.clone()];
+        }
+        // 2. find possible permutations for unknowns
+        let permutations = repeat_n(
+            vec![SpringState::Operational, SpringState::Damaged],
+            unknowns,
+        )
+        .multi_cartesian_product();
+        // 3. Fill unknowns with possible states
+        permutations
+            .map(|p| {
+                let mut new_row = self.springs.clone();
+                let mut perm_idx = 0;
+                for state in new_row.iter_mut() {
+                    if *state == SpringState::Unknown {
+                        *state = p[perm_idx];
+                        perm_idx += 1;
+                    }
+                }
+                new_row
+            })
+            .collect::<Vec<Vec<SpringState>>>()
+    }
+
+    /// Whether a row with no unknowns has exactly these runs of damaged springs.
+    pub fn check_row(row: &Vec<SpringState>, damaged_count: &Vec<u64>) -> bool {
+        let mut damage_in_row = Vec::new();
+        let mut contiguous_count = 0;
+        for state in row {
+            if *state == SpringState::Damaged {
+                contiguous_count += 1;
+            } else {
+                if contiguous_count > 0 {
+                    damage_in_row.push(contiguous_count);
+                }
+                contiguous_count = 0;
+            }
+        }
+        if contiguous_count != 0 {
+            damage_in_row.push(contiguous_count);
+        }
+        let not_matched = damage_in_row
+            .iter()
+            .zip(damaged_count)
+            .filter(|(a, b)| a != b)
+            .count();
+        damage_in_row.len() == damaged_count.len() && not_matched == 0
+    }
+
+    /// Counts the arrangements that fit by trying every one, see [`SpringRow::count`].
+    pub fn count_completion_options(&self) -> usize {
+        let options = self.generate_possible_completions();
+        options
+            .iter()
+            .filter(|opt| SpringRow::check_row(opt, &self.damaged_count))
+            .count()
+    }
+
+    // part 2 repeats the springs `factor` times separated by unknowns and the counts `factor` times
+    pub fn unfold(&self, factor: usize) -> SpringRow {
+        SpringRow {
+            springs: Itertools::intersperse(
+                std::iter::repeat_n(self.springs.clone(), factor),
+                vec![SpringState::Unknown],
+            )
+            .flatten()
+            .collect::<Vec<SpringState>>(),
+            damaged_count: std::iter::repeat_n(self.damaged_count.clone(), factor)
+                .flatten()
+                .collect::<Vec<u64>>(),
+        }
+    }
+
+    /// Counts the ways `states` can hold the runs in `damaged`, memoised in `cache`.
+    pub fn count_rec(
+        states: &[SpringState],
+        damaged: &[u64],
+        cache: &mut HashMap<String, usize>,
+    ) -> usize {
+        if states.is_empty() {
+            if damaged.is_empty() {
+                return 1;
+            }
+            return 0;
+        }
+        if damaged.is_empty() {
+            if states.contains(&SpringState::Damaged) {
+                return 0;
+            }
+            return 1;
+        }
+        let key = SpringRow {
+            springs: states.to_vec(),
+            damaged_count: damaged.to_vec(),
+        }
+        .to_string();
+        if let Some(cache_entry) = cache.get(&key) {
+            return *cache_entry;
+        }
+        let mut result = 0;
+        if states[0] == SpringState::Unknown || states[0] == SpringState::Operational {
+            result += SpringRow::count_rec(&states[1..], damaged, cache);
+        }
+        if (states[0] == SpringState::Unknown || states[0] == SpringState::Damaged)
+            && damaged[0] as usize <= states.len()
+            && !states
+                .iter()
+                .take(damaged[0] as usize)
+                .contains(&SpringState::Operational)
+            && (damaged[0] as usize == states.len()
+                || states[damaged[0] as usize] != SpringState::Damaged)
+        {
+            let state_start_bound = if damaged[0] as usize == states.len() {
+                damaged[0]
+            } else {
+                damaged[0] + 1
+            };
+            result +=
+                SpringRow::count_rec(&states[state_start_bound as usize..], &damaged[1..], cache);
+        }
+        cache.insert(key, result);
+        result
+    }
+
+    /// Counts the arrangements that fit, quickly enough for unfolded rows.
+    pub fn count(&self) -> usize {
+        SpringRow::count_rec(
+            &self.springs[..],
+            &self.damaged_count[..],
+            &mut HashMap::new(),
+        )
+    }
+}
+
+pub type SpringField = Vec<SpringRow>;
+
+fn parse_input(input: &str) -> IResult<'_, SpringField> {
+    let (input, str_field) = separated_list1(
+        line_ending,
+        separated_pair(
+            is_a(".#?").context("springs"),
+            space1,
+            separated_list1(tag(","), complete::u64).context("damaged counts"),
+        ),
+    )(input)?;
+    let field = str_field
+        .into_iter()
+        .map(|(springs, damaged_count)| SpringRow {
+            springs: springs
+                .chars()
+                .map(|s| match s {
+                    '?' => SpringState::Unknown,
+                    '#' => SpringState::Damaged,
+                    '.' => SpringState::Operational,
+                    _ => unreachable!("springs are checked when parsing"),
+                })
+                .collect::<Vec<SpringState>>(),
+            damaged_count,
+        })
+        .collect::<SpringField>();
+    Ok((input, field))
+}
+
+pub struct Day12;
+
+aoc_common::export_plugin!(Day12);
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 12;
+
+    type Parsed = SpringField;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        finish(input, parse_input)
+    }
+
+    fn part1(field: &Self::Parsed) -> Result<usize> {
+        Ok(field
+            .iter()
+            .map(|row| row.count_completion_options())
+            .sum::<usize>())
+    }
+
+    fn part2(field: &Self::Parsed) -> Result<usize> {
+        Ok(field.iter().map(|row| row.unfold(5).count()).sum::<usize>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::example_answer;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn part1_example_case() {
+        let parsed = Day12::parse_file("input/p1.example.txt").expect("example input should parse");
+        let result = Day12::part1(&parsed).expect("example should have an answer");
+        let expected =
+            example_answer("input/p1.example.txt", 1).expect("example should have a part 1 answer");
+        assert_eq!(result.to_string(), expected);
+    }
+
+    #[test]
+    fn part2_example_case() {
+        let parsed = Day12::parse_file("input/p1.example.txt").expect("example input should parse");
+        let result = Day12::part2(&parsed).expect("example should have an answer");
+        let expected =
+            example_answer("input/p1.example.txt", 2).expect("example should have a part 2 answer");
+        assert_eq!(result.to_string(), expected);
+    }
+
+    // a row of known springs with its real damaged counts, then some springs hidden again, so
+    // every generated row has at least one completion
+    fn spring_row(max_len: usize) -> impl Strategy<Value = SpringRow> {
+        prop::collection::vec((any::<bool>(), prop::bool::weighted(0.4)), 1..=max_len).prop_map(
+            |cells| {
+                let damaged_count = cells
+                    .chunk_by(|a, b| a.0 == b.0)
+                    .filter(|run| run[0].0)
+                    .map(|run| run.len() as u64)
+                    .collect();
+                let springs = cells
+                    .iter()
+                    .map(|(damaged, hidden)| match (damaged, hidden) {
+                        (_, true) => SpringState::Unknown,
+                        (true, false) => SpringState::Damaged,
+                        (false, false) => SpringState::Operational,
+                    })
+                    .collect();
+                SpringRow {
+                    springs,
+                    damaged_count,
+                }
+            },
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn memoised_count_matches_brute_force(row in spring_row(14)) {
+            let brute_force = row.count_completion_options();
+            prop_assert!(brute_force >= 1);
+            prop_assert_eq!(row.count(), brute_force);
+            prop_assert_eq!(row.unfold(1).count(), brute_force);
+        }
+
+        #[test]
+        fn unfolded_count_matches_brute_force(row in spring_row(6)) {
+            let unfolded = row.unfold(2);
+            prop_assert_eq!(unfolded.count(), unfolded.count_completion_options());
+        }
+    }
+}
@@ -0,0 +1,33 @@
+// solvers that can be watched: they hand each step of their work to a player as a frame, and
+// the player decides how to show it and whether to carry on
+
+use std::ops::ControlFlow;
+
+use crate::{Colour, Grid, Result, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub symbol: char,
+    pub colour: Colour,
+}
+
+impl Cell {
+    pub fn new(symbol: char, colour: Colour) -> Self {
+        Cell { symbol, colour }
+    }
+}
+
+/// One step of an animation: the grid as it stands and a line about what's happening.
+#[derive(Debug, Clone)]
+pub struct Frame {
+    pub cells: Grid<Cell>,
+    pub caption: String,
+}
+
+/// Receives each frame in turn, breaking to stop the animation early.
+pub type Player<'a> = &'a mut dyn FnMut(Frame) -> ControlFlow<()>;
+
+/// A day whose solution can be played back step by step.
+pub trait Animate: Solution {
+    fn animate(parsed: &Self::Parsed, player: Player<'_>) -> Result<()>;
+}
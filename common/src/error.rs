@@ -0,0 +1,88 @@
+use std::{
+    fmt, io,
+    path::{Path, PathBuf},
+};
+
+use nom_supreme::final_parser::{Location, RecreateContext};
+use thiserror::Error;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("unable to read {}: {source}", path.display())]
+    Io { path: PathBuf, source: io::Error },
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+    // the input parsed, but doesn't describe a puzzle that has an answer
+    #[error("{0}")]
+    Unsolvable(String),
+    // an argument to one of a day's own commands that it can't use, like a seed that isn't a
+    // number for day 5's `map`
+    #[error("{0}")]
+    InvalidArgument(String),
+}
+
+impl Error {
+    /// Attaches the path the input was read from, so parse errors can point into the file.
+    pub fn in_file(self, path: impl AsRef<Path>) -> Self {
+        match self {
+            Error::Parse(err) => Error::Parse(ParseError {
+                file: Some(path.as_ref().to_path_buf()),
+                ..err
+            }),
+            err => err,
+        }
+    }
+}
+
+/// Where and why an input stopped parsing. Lines and columns are 1-indexed.
+#[derive(Debug, Error)]
+pub struct ParseError {
+    pub file: Option<PathBuf>,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    // outermost first
+    pub context: Vec<&'static str>,
+    pub source_line: String,
+}
+
+impl ParseError {
+    /// Builds an error for a hand-written parser, where `tail` is the unparsed remainder of
+    /// `input` at the point things went wrong.
+    pub fn at(input: &str, tail: &str, expected: impl Into<String>) -> Self {
+        let Location { line, column } = Location::recreate_context(input, tail);
+        let source_line = input.lines().nth(line - 1).unwrap_or_default().to_string();
+        ParseError {
+            file: None,
+            line,
+            column,
+            expected: expected.into(),
+            context: Vec::new(),
+            source_line,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let file = match &self.file {
+            Some(path) if path == Path::new("-") => "<stdin>".to_string(),
+            Some(path) => path.display().to_string(),
+            None => "<input>".to_string(),
+        };
+        write!(
+            f,
+            "{file}:{}:{}: expected {}",
+            self.line, self.column, self.expected
+        )?;
+        if !self.context.is_empty() {
+            write!(f, " while parsing {}", self.context.join(" > "))?;
+        }
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(f)?;
+        writeln!(f, "{} | {}", self.line, self.source_line)?;
+        write!(f, "{gutter} | {}^", " ".repeat(self.column - 1))
+    }
+}
@@ -0,0 +1,106 @@
+// the answers an example should give, kept next to it as `<name>.answers.toml`. `aoc examples`
+// writes them from the puzzle page, and the days' example tests read them from there rather
+// than repeating them
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, ParseError, Result};
+
+/// The expected answers for one example, either of which the puzzle page may not give.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ExampleAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+}
+
+impl ExampleAnswers {
+    /// Where the answers for `example` are kept, e.g. `p1.example.answers.toml` for
+    /// `p1.example.txt`.
+    pub fn path(example: impl AsRef<Path>) -> PathBuf {
+        example.as_ref().with_extension("answers.toml")
+    }
+
+    /// Reads the answers kept next to `example`.
+    pub fn load(example: impl AsRef<Path>) -> Result<Self> {
+        let path = Self::path(example);
+        let contents = fs::read_to_string(&path).map_err(|source| Error::Io {
+            path: path.clone(),
+            source,
+        })?;
+        toml::from_str(&contents).map_err(|err| {
+            let tail = &contents[err.span().map_or(0, |span| span.start)..];
+            Error::Parse(ParseError::at(&contents, tail, err.message())).in_file(&path)
+        })
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            _ => self.part2.as_deref(),
+        }
+    }
+
+    pub fn set(&mut self, part: u8, answer: String) {
+        match part {
+            1 => self.part1 = Some(answer),
+            _ => self.part2 = Some(answer),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.part1.is_none() && self.part2.is_none()
+    }
+}
+
+/// The answer `example` should give for `part`, for a day's tests to compare against.
+pub fn example_answer(example: impl AsRef<Path>, part: u8) -> Result<String> {
+    let example = example.as_ref();
+    ExampleAnswers::load(example)?
+        .get(part)
+        .map(str::to_string)
+        .ok_or_else(|| {
+            Error::Unsolvable(format!(
+                "{} has no part {part} answer",
+                ExampleAnswers::path(example).display()
+            ))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_the_answers_next_to_an_example() {
+        let dir = tempfile::tempdir().expect("temp dir should be created");
+        let example = dir.path().join("p1.example2.txt");
+        assert_eq!(
+            ExampleAnswers::path(&example),
+            dir.path().join("p1.example2.answers.toml")
+        );
+        let err = example_answer(&example, 1).expect_err("there are no answers yet");
+        assert!(err.to_string().starts_with("unable to read"), "{err}");
+
+        fs::write(ExampleAnswers::path(&example), "part1 = \"142\"\n")
+            .expect("answers should be written");
+        assert_eq!(example_answer(&example, 1).expect("part 1 is known"), "142");
+        let err = example_answer(&example, 2).expect_err("part 2 isn't known");
+        assert!(err.to_string().ends_with("has no part 2 answer"), "{err}");
+
+        fs::write(ExampleAnswers::path(&example), "part3 = \"1\"\n")
+            .expect("answers should be written");
+        let err = ExampleAnswers::load(&example).expect_err("part 3 isn't a part");
+        assert!(
+            err.to_string().contains("p1.example2.answers.toml:1:1:"),
+            "{err}"
+        );
+    }
+}
@@ -0,0 +1,16 @@
+// commands a day adds to `aoc repl` for poking at its parsed input, on top of the `show`,
+// `part1` and `part2` every day has
+
+use crate::{Result, Solution};
+
+/// A day with its own REPL commands, like following one seed through day 5's maps.
+pub trait Explore: Solution {
+    /// Each command's usage and what it does, as listed by the REPL's `help`.
+    const COMMANDS: &'static [(&'static str, &'static str)];
+
+    /// Runs `command` with the rest of the line as `arg`, or returns `None` if the day has no
+    /// such command. An `arg` the command can't use is an [`Error::InvalidArgument`].
+    ///
+    /// [`Error::InvalidArgument`]: crate::Error::InvalidArgument
+    fn explore(parsed: &Self::Parsed, command: &str, arg: &str) -> Option<Result<String>>;
+}
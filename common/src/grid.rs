@@ -0,0 +1,335 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::{ParseError, Result};
+
+/// A `(row, column)` position in a [`Grid`], counted from the top left.
+pub type Pos = (usize, usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn opposite(self) -> Direction {
+        match self {
+            Direction::North => Direction::South,
+            Direction::East => Direction::West,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+        }
+    }
+
+    // (rows, columns) moved by one step
+    fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::North => (-1, 0),
+            Direction::East => (0, 1),
+            Direction::South => (1, 0),
+            Direction::West => (0, -1),
+        }
+    }
+}
+
+/// A rectangular grid, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its rows, which must all be the same length.
+    pub fn from_rows(rows: impl IntoIterator<Item = Vec<T>>) -> Self {
+        let mut grid = Grid {
+            width: 0,
+            height: 0,
+            cells: Vec::new(),
+        };
+        for row in rows {
+            if grid.height == 0 {
+                grid.width = row.len();
+            }
+            assert_eq!(
+                row.len(),
+                grid.width,
+                "grid rows should all be the same length"
+            );
+            grid.cells.extend(row);
+            grid.height += 1;
+        }
+        grid
+    }
+
+    /// Parses one cell per character with `cell`, which returns `None` for characters that
+    /// aren't `expected`. Blank lines are skipped, and rows must all be the same length.
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self> {
+        let mut grid = Grid {
+            width: 0,
+            height: 0,
+            cells: Vec::new(),
+        };
+        for line in input.lines().filter(|line| !line.is_empty()) {
+            let start = grid.cells.len();
+            for (idx, c) in line.char_indices() {
+                let Some(cell) = cell(c) else {
+                    return Err(ParseError::at(input, &line[idx..], expected).into());
+                };
+                grid.cells.push(cell);
+            }
+            let len = grid.cells.len() - start;
+            if grid.height == 0 {
+                grid.width = len;
+            } else if len != grid.width {
+                let tail = line
+                    .char_indices()
+                    .nth(grid.width)
+                    .map_or(&line[line.len()..], |(idx, _)| &line[idx..]);
+                let expected = format!("{} columns", grid.width);
+                return Err(ParseError::at(input, tail, expected).into());
+            }
+            grid.height += 1;
+        }
+        Ok(grid)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (row, col): Pos) -> Option<&T> {
+        (row < self.height && col < self.width).then(|| &self.cells[row * self.width + col])
+    }
+
+    pub fn get_mut(&mut self, (row, col): Pos) -> Option<&mut T> {
+        (row < self.height && col < self.width).then(|| &mut self.cells[row * self.width + col])
+    }
+
+    /// The position one step from `pos` in `direction`, if that's still inside the grid.
+    pub fn step(&self, (row, col): Pos, direction: Direction) -> Option<Pos> {
+        let (rows, cols) = direction.offset();
+        let row = row
+            .checked_add_signed(rows)
+            .filter(|row| *row < self.height)?;
+        let col = col
+            .checked_add_signed(cols)
+            .filter(|col| *col < self.width)?;
+        Some((row, col))
+    }
+
+    /// The up to 4 positions orthogonally next to `pos`.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(pos, direction))
+    }
+
+    /// The up to 8 positions next to `pos`, including diagonally.
+    pub fn neighbours8(&self, (row, col): Pos) -> impl Iterator<Item = Pos> + '_ {
+        (-1..=1)
+            .flat_map(|rows| (-1..=1).map(move |cols| (rows, cols)))
+            .filter(|offset| *offset != (0, 0))
+            .filter_map(move |(rows, cols)| {
+                let row = row
+                    .checked_add_signed(rows)
+                    .filter(|row| *row < self.height)?;
+                let col = col
+                    .checked_add_signed(cols)
+                    .filter(|col| *col < self.width)?;
+                Some((row, col))
+            })
+    }
+
+    /// Every position in the grid, row by row. The iterator doesn't borrow the grid, so cells
+    /// can be updated while walking it.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The first position, row by row, whose cell matches `predicate`.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Pos> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "column {col} is outside the grid");
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Swaps rows and columns.
+    pub fn transpose(&self) -> Self {
+        Grid::from_rows(self.columns().map(|col| col.cloned().collect()))
+    }
+
+    /// Rotates a quarter turn clockwise, so the first column becomes the first row reversed.
+    pub fn rotate_right(&self) -> Self {
+        Grid::from_rows(self.columns().map(|col| {
+            let mut row = col.cloned().collect::<Vec<_>>();
+            row.reverse();
+            row
+        }))
+    }
+
+    /// Rotates a quarter turn anticlockwise, so the last column becomes the first row.
+    pub fn rotate_left(&self) -> Self {
+        Grid::from_rows(
+            (0..self.width)
+                .rev()
+                .map(|col| self.column(col).cloned().collect()),
+        )
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside the grid"))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chars(input: &str) -> Grid<char> {
+        Grid::parse(input, "any character", Some).expect("grid should parse")
+    }
+
+    #[test]
+    fn parses_and_indexes() {
+        let grid =
+            Grid::parse("12\n34\n56\n", "a digit", |c| c.to_digit(10)).expect("grid should parse");
+        assert_eq!((grid.width(), grid.height()), (2, 3));
+        assert_eq!(grid[(2, 0)], 5);
+        assert_eq!(grid.get((0, 2)), None);
+        assert_eq!(grid.position(|cell| *cell == 4), Some((1, 1)));
+        assert_eq!(grid.row(1), &[3, 4]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 4, 6]);
+    }
+
+    #[test]
+    fn reports_bad_cells_and_ragged_rows() {
+        let err =
+            Grid::parse("12\n3x\n", "a digit", |c| c.to_digit(10)).expect_err("x is not a digit");
+        assert!(
+            err.to_string().starts_with("<input>:2:2: expected a digit"),
+            "{err}"
+        );
+        let err = Grid::parse("12\n345\n", "a digit", |c| c.to_digit(10))
+            .expect_err("rows should be the same length");
+        assert!(
+            err.to_string()
+                .starts_with("<input>:2:3: expected 2 columns"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn neighbours_stay_in_bounds() {
+        let grid = chars("abc\ndef\nghi\n");
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbours8((2, 2)).collect::<Vec<_>>(),
+            vec![(1, 1), (1, 2), (2, 1)]
+        );
+        assert_eq!(grid.step((0, 1), Direction::North), None);
+        assert_eq!(grid.step((0, 1), Direction::South), Some((1, 1)));
+    }
+
+    #[test]
+    fn transposes_and_rotates() {
+        let grid = chars("abc\ndef\n");
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_right().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_left().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_right().rotate_left(), grid);
+    }
+}
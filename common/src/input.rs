@@ -0,0 +1,75 @@
+use std::{
+    fs,
+    io::{self, Read},
+    path::Path,
+};
+
+use crate::{Error, Result};
+
+/// Reads the whole puzzle input at `path` into a string, or stdin when `path` is `-`.
+pub fn read_input(path: impl AsRef<Path>) -> Result<String> {
+    let path = path.as_ref();
+    let result = if path == Path::new("-") {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input).map(|_| input)
+    } else {
+        fs::read_to_string(path)
+    };
+    result.map_err(|source| Error::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Reads the puzzle input at `path` as one string per line.
+pub fn read_lines(path: impl AsRef<Path>) -> Result<Vec<String>> {
+    Ok(lines(&read_input(path)?))
+}
+
+/// Reads the puzzle input at `path` as a grid of characters, one row per line.
+pub fn read_char_grid(path: impl AsRef<Path>) -> Result<Vec<Vec<char>>> {
+    Ok(char_grid(&read_input(path)?))
+}
+
+/// Reads the puzzle input at `path` as a grid of bytes, one row per line.
+pub fn read_byte_grid(path: impl AsRef<Path>) -> Result<Vec<Vec<u8>>> {
+    Ok(byte_grid(&read_input(path)?))
+}
+
+pub fn lines(input: &str) -> Vec<String> {
+    input.lines().map(|line| line.to_string()).collect()
+}
+
+pub fn char_grid(input: &str) -> Vec<Vec<char>> {
+    input.lines().map(|line| line.chars().collect()).collect()
+}
+
+pub fn byte_grid(input: &str) -> Vec<Vec<u8>> {
+    input.lines().map(|line| line.as_bytes().to_vec()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+
+    #[test]
+    fn grids_and_lines() {
+        let mut file = tempfile::NamedTempFile::new().expect("temp file should be created");
+        write!(file, "ab.\n#cd\n").expect("temp file should be writable");
+        let lines = read_lines(file.path()).expect("input should be readable");
+        assert_eq!(lines, vec!["ab.", "#cd"]);
+        let chars = read_char_grid(file.path()).expect("input should be readable");
+        assert_eq!(chars[1], vec!['#', 'c', 'd']);
+        let bytes = read_byte_grid(file.path()).expect("input should be readable");
+        assert_eq!(bytes[0], b"ab.".to_vec());
+    }
+
+    #[test]
+    fn missing_file() {
+        let err = read_input("input/does-not-exist.txt").expect_err("file should not exist");
+        assert!(matches!(err, Error::Io { .. }));
+        assert!(err.to_string().contains("input/does-not-exist.txt"));
+    }
+}
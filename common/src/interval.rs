@@ -0,0 +1,245 @@
+use std::{
+    cmp::{max, min},
+    ops::{Add, Range, Sub},
+};
+
+/// A set of values stored as sorted, disjoint half-open ranges, so huge ranges cost no more
+/// than small ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeSet<T> {
+    // sorted by start, with no two ranges overlapping or touching
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> Default for RangeSet<T> {
+    fn default() -> Self {
+        RangeSet { ranges: Vec::new() }
+    }
+}
+
+impl<T: Ord + Copy> RangeSet<T> {
+    pub fn new() -> Self {
+        RangeSet::default()
+    }
+
+    fn normalised(mut ranges: Vec<Range<T>>) -> Self {
+        ranges.retain(|range| range.start < range.end);
+        ranges.sort_by_key(|range| range.start);
+        let mut merged: Vec<Range<T>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = max(last.end, range.end),
+                _ => merged.push(range),
+            }
+        }
+        RangeSet { ranges: merged }
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        let mut ranges = std::mem::take(&mut self.ranges);
+        ranges.push(range);
+        *self = RangeSet::normalised(ranges);
+    }
+
+    /// The disjoint ranges making up the set, in order.
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.ranges.iter().any(|range| range.contains(&value))
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    pub fn union(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        RangeSet::normalised(self.ranges.iter().chain(&other.ranges).cloned().collect())
+    }
+
+    pub fn intersection(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let (mut i, mut j) = (0, 0);
+        let mut ranges = Vec::new();
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let (start, end) = (max(a.start, b.start), min(a.end, b.end));
+            if start < end {
+                ranges.push(start..end);
+            }
+            // whichever ends first can't overlap anything further along the other set
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        RangeSet { ranges }
+    }
+
+    /// The values in `self` that aren't in `other`.
+    pub fn difference(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut ranges = Vec::new();
+        for range in &self.ranges {
+            let mut start = range.start;
+            for cut in &other.ranges {
+                if cut.start >= range.end {
+                    break;
+                }
+                if cut.end <= start {
+                    continue;
+                }
+                if cut.start > start {
+                    ranges.push(start..cut.start);
+                }
+                start = cut.end;
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+        RangeSet { ranges }
+    }
+
+    /// Splits the set into the values below `at` and the rest.
+    pub fn split_at(&self, at: T) -> (RangeSet<T>, RangeSet<T>) {
+        let (mut below, mut rest) = (Vec::new(), Vec::new());
+        for range in &self.ranges {
+            if range.end <= at {
+                below.push(range.clone());
+            } else if range.start >= at {
+                rest.push(range.clone());
+            } else {
+                below.push(range.start..at);
+                rest.push(at..range.end);
+            }
+        }
+        (RangeSet { ranges: below }, RangeSet { ranges: rest })
+    }
+}
+
+impl<T: Ord + Copy> From<Range<T>> for RangeSet<T> {
+    fn from(range: Range<T>) -> Self {
+        RangeSet::normalised(vec![range])
+    }
+}
+
+impl<T: Ord + Copy> FromIterator<Range<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        RangeSet::normalised(iter.into_iter().collect())
+    }
+}
+
+/// A mapping that shifts each source range to start somewhere else, leaving values outside
+/// every source range as they are. Where sources overlap, the piece added first wins.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PiecewiseMap<T> {
+    // source range and where it starts once mapped
+    pieces: Vec<(Range<T>, T)>,
+}
+
+impl<T> Default for PiecewiseMap<T> {
+    fn default() -> Self {
+        PiecewiseMap { pieces: Vec::new() }
+    }
+}
+
+impl<T> PiecewiseMap<T>
+where
+    T: Ord + Copy + Add<Output = T> + Sub<Output = T>,
+{
+    pub fn new() -> Self {
+        PiecewiseMap::default()
+    }
+
+    /// Maps `source` onto the range of the same length starting at `destination`.
+    pub fn insert(&mut self, source: Range<T>, destination: T) {
+        self.pieces.push((source, destination));
+    }
+
+    pub fn map(&self, value: T) -> T {
+        self.pieces
+            .iter()
+            .find(|(source, _)| source.contains(&value))
+            .map_or(value, |(source, destination)| {
+                value - source.start + *destination
+            })
+    }
+
+    /// Maps every value in `set` at once, a range at a time rather than value by value.
+    pub fn map_set(&self, set: &RangeSet<T>) -> RangeSet<T> {
+        let mut unmapped = set.clone();
+        let mut mapped = Vec::new();
+        for (source, destination) in &self.pieces {
+            let source_set = RangeSet::from(source.clone());
+            for range in unmapped.intersection(&source_set).ranges() {
+                let start = range.start - source.start + *destination;
+                mapped.push(start..range.end - source.start + *destination);
+            }
+            unmapped = unmapped.difference(&source_set);
+        }
+        mapped.extend(unmapped.ranges);
+        RangeSet::normalised(mapped)
+    }
+}
+
+impl<T> FromIterator<(Range<T>, T)> for PiecewiseMap<T> {
+    fn from_iter<I: IntoIterator<Item = (Range<T>, T)>>(iter: I) -> Self {
+        PiecewiseMap {
+            pieces: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[Range<i32>]) -> RangeSet<i32> {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    fn merges_overlapping_and_touching_ranges() {
+        let mut ranges = set(&[5..8, 0..2, 1..3, 8..10, 12..12]);
+        assert_eq!(ranges.ranges(), &[0..3, 5..10]);
+        ranges.insert(3..5);
+        assert_eq!(ranges, RangeSet::from(0..10));
+        assert!(ranges.contains(9));
+        assert!(!ranges.contains(10));
+        assert_eq!(ranges.min(), Some(0));
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[0..10, 20..30]);
+        let b = set(&[5..25, 28..40]);
+        assert_eq!(a.union(&b), RangeSet::from(0..40));
+        assert_eq!(a.intersection(&b).ranges(), &[5..10, 20..25, 28..30]);
+        assert_eq!(a.difference(&b).ranges(), &[0..5, 25..28]);
+        assert_eq!(b.difference(&a).ranges(), &[10..20, 30..40]);
+        assert!(a.difference(&a).is_empty());
+
+        let (below, rest) = a.split_at(25);
+        assert_eq!(below.ranges(), &[0..10, 20..25]);
+        assert_eq!(rest, RangeSet::from(25..30));
+    }
+
+    #[test]
+    fn maps_whole_ranges_like_each_value() {
+        let map = [(10..20, 100), (15..25, 0), (30..35, 12)]
+            .into_iter()
+            .collect::<PiecewiseMap<i32>>();
+        assert_eq!(map.map(5), 5);
+        assert_eq!(map.map(12), 102);
+        assert_eq!(map.map(22), 7);
+        assert_eq!(map.map(31), 13);
+
+        let input = RangeSet::from(0..40);
+        let expected = (0..40).map(|value| map.map(value)..map.map(value) + 1);
+        assert_eq!(map.map_set(&input), expected.collect());
+    }
+}
@@ -0,0 +1,26 @@
+pub mod animate;
+pub mod error;
+pub mod example;
+pub mod explore;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod math;
+pub mod output;
+pub mod parse;
+pub mod plugin;
+pub mod render;
+pub mod search;
+pub mod solution;
+
+pub use animate::{Animate, Cell, Frame, Player};
+pub use error::{Error, ParseError, Result};
+pub use example::{example_answer, ExampleAnswers};
+pub use explore::Explore;
+pub use grid::{Direction, Grid, Pos};
+pub use input::{
+    byte_grid, char_grid, lines, read_byte_grid, read_char_grid, read_input, read_lines,
+};
+pub use output::{Format, Report};
+pub use render::{Canvas, Colour, Render};
+pub use solution::{run_bin, Solution};
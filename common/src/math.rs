@@ -0,0 +1,162 @@
+// number theory for puzzles built on repeating cycles; the CRT works in i128 internally so
+// combining moduli near i64::MAX has room to spare, and gives up rather than overflowing once
+// the combined modulus is too big for that
+
+/// The greatest common divisor, always non-negative. `gcd(0, 0)` is 0.
+pub fn gcd(a: i64, b: i64) -> i64 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// The least common multiple, always non-negative. It's 0 if either number is.
+pub fn lcm(a: i64, b: i64) -> i64 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    (a / gcd(a, b) * b).abs()
+}
+
+/// The gcd of every value, 0 for none.
+pub fn gcd_all(values: &[i64]) -> i64 {
+    values.iter().fold(0, |acc, value| gcd(acc, *value))
+}
+
+/// The lcm of every value, 1 for none.
+pub fn lcm_all(values: &[i64]) -> i64 {
+    values.iter().fold(1, |acc, value| lcm(acc, *value))
+}
+
+/// Returns `(g, x, y)` where `g` is the gcd of `a` and `b` and `a * x + b * y == g`.
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The `x` in `0..m` with `a * x ≡ 1 (mod m)`, if `a` and `m` are coprime.
+pub fn mod_inverse(a: i64, m: i64) -> Option<i64> {
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// Solves the congruences `x ≡ remainder (mod modulus)` for each `(remainder, modulus)` pair.
+/// The moduli needn't be coprime. Returns the smallest non-negative solution with the lcm of
+/// the moduli, which every other solution differs from by a multiple of, or `None` if the
+/// congruences contradict each other or the lcm doesn't fit in an `i64`.
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let (mut x, mut m) = (0i128, 1i128);
+    for &(remainder, modulus) in congruences {
+        assert!(modulus > 0, "moduli should be positive, got {modulus}");
+        let (remainder, modulus) = (i128::from(remainder), i128::from(modulus));
+        let (g, p) = bezout(m, modulus);
+        let diff = remainder - x;
+        if diff % g != 0 {
+            return None;
+        }
+        // x + m * k ≡ remainder (mod modulus), with k = diff / g * p (mod modulus / g)
+        let step = modulus / g;
+        let k = (diff / g % step * p % step).rem_euclid(step);
+        x = x.checked_add(m.checked_mul(k)?)?;
+        m = m.checked_mul(step)?;
+        x = x.rem_euclid(m);
+    }
+    let x = i64::try_from(x).ok()?;
+    let m = i64::try_from(m).ok()?;
+    Some((x, m))
+}
+
+// the gcd of `a` and `b` with the `x` from `a * x + b * y == gcd`
+fn bezout(a: i128, b: i128) -> (i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+    }
+    (old_r, old_x)
+}
+
+/// The first time every cycle is at a hit, where a cycle first hits at `offset` and then
+/// every `period` after that, or `None` if they never coincide. Unlike a plain lcm of the
+/// periods, this is right when the first hit doesn't line up with the period.
+pub fn cycles_align(cycles: &[(i64, i64)]) -> Option<i64> {
+    let (x, m) = crt(cycles)?;
+    // the solution has to come after every cycle's first hit
+    let start = cycles.iter().map(|(offset, _)| *offset).max().unwrap_or(0);
+    if x >= start {
+        return Some(x);
+    }
+    let periods = (start - x + m - 1) / m;
+    x.checked_add(periods.checked_mul(m)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!(gcd(0, 5), 5);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+        assert_eq!(gcd_all(&[12, 18, 27]), 3);
+        assert_eq!(lcm_all(&[2, 3, 4]), 12);
+        assert_eq!(lcm_all(&[]), 1);
+    }
+
+    #[test]
+    fn extended_gcd_and_inverse() {
+        let (g, x, y) = extended_gcd(240, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(4, 8), None);
+    }
+
+    #[test]
+    fn crt_with_and_without_coprime_moduli() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[(-1, 5)]), Some((4, 5)));
+        assert_eq!(crt(&[]), Some((0, 1)));
+        let big = 1_000_000_007;
+        let (x, m) = crt(&[(1, big), (2, big + 2)]).expect("coprime moduli always have a solution");
+        assert_eq!(m, big * (big + 2));
+        assert_eq!((x % big, x % (big + 2)), (1, 2));
+        // three primes near i64::MAX, whose lcm is far beyond even i128
+        let primes = [
+            (1, 9223372036854775783),
+            (2, 9223372036854775643),
+            (3, 9223372036854775549),
+        ];
+        assert_eq!(crt(&primes), None);
+    }
+
+    #[test]
+    fn cycles_align_after_their_first_hits() {
+        // the lcm of the periods, 6, would be right if the offsets lined up
+        assert_eq!(cycles_align(&[(2, 2), (3, 3)]), Some(6));
+        // first hits at 5 and 7, then every 4 and 6: 5, 9, 13, 17... and 7, 13...
+        assert_eq!(cycles_align(&[(5, 4), (7, 6)]), Some(13));
+        assert_eq!(cycles_align(&[(1, 2), (2, 4)]), None);
+    }
+}
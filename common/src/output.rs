@@ -0,0 +1,133 @@
+use std::{fmt, str::FromStr, time::Duration};
+
+use serde::{Serialize, Serializer};
+
+/// How a solved part is reported.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// Just the answer, for piping into other tools.
+    #[default]
+    Plain,
+    /// The answer labelled with its day and part, and how long it took.
+    Human,
+    /// One JSON object per answer.
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "plain" => Ok(Format::Plain),
+            "human" => Ok(Format::Human),
+            "json" => Ok(Format::Json),
+            _ => Err(format!(
+                "unknown format {s:?}, expected plain, human or json"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Format::Plain => write!(f, "plain"),
+            Format::Human => write!(f, "human"),
+            Format::Json => write!(f, "json"),
+        }
+    }
+}
+
+/// One solved part. The parse time is that of the input shared by both parts, or `None` when
+/// parsing isn't timed apart from solving, as with plugins.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Report {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    #[serde(
+        rename = "parse_ns",
+        serialize_with = "maybe_nanos",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub parse: Option<Duration>,
+    #[serde(rename = "solve_ns", serialize_with = "nanos")]
+    pub solve: Duration,
+}
+
+fn nanos<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(duration.as_nanos())
+}
+
+fn maybe_nanos<S: Serializer>(
+    duration: &Option<Duration>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match duration {
+        Some(duration) => nanos(duration, serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+impl Report {
+    pub fn render(&self, format: Format) -> String {
+        match format {
+            Format::Plain => self.answer.clone(),
+            Format::Human => {
+                let parse = self
+                    .parse
+                    .map(|parse| format!("parse {parse:.2?}, "))
+                    .unwrap_or_default();
+                format!(
+                    "{} day {:02} part {}: {} ({parse}solve {:.2?})",
+                    self.year, self.day, self.part, self.answer, self.solve
+                )
+            }
+            Format::Json => serde_json::to_string(self).expect("a report should always serialise"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_each_format() {
+        let report = Report {
+            year: 2023,
+            day: 5,
+            part: 2,
+            answer: "46".to_string(),
+            parse: Some(Duration::from_micros(1500)),
+            solve: Duration::from_nanos(250),
+        };
+        assert_eq!(report.render(Format::Plain), "46");
+        assert_eq!(
+            report.render(Format::Human),
+            "2023 day 05 part 2: 46 (parse 1.50ms, solve 250.00ns)"
+        );
+        assert_eq!(
+            report.render(Format::Json),
+            r#"{"year":2023,"day":5,"part":2,"answer":"46","parse_ns":1500000,"solve_ns":250}"#
+        );
+
+        // plugins parse and solve in one call, so there's no parse time to report
+        let report = Report {
+            parse: None,
+            ..report
+        };
+        assert_eq!(
+            report.render(Format::Human),
+            "2023 day 05 part 2: 46 (solve 250.00ns)"
+        );
+        assert_eq!(
+            report.render(Format::Json),
+            r#"{"year":2023,"day":5,"part":2,"answer":"46","solve_ns":250}"#
+        );
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert!("yaml".parse::<Format>().is_err());
+    }
+}
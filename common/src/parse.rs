@@ -0,0 +1,139 @@
+use nom::{character::complete::multispace0, sequence::terminated, Parser};
+use nom_supreme::error::{BaseErrorKind, ErrorTree, StackContext};
+
+use crate::{ParseError, Result};
+
+pub type IResult<'a, T> = nom::IResult<&'a str, T, ErrorTree<&'a str>>;
+
+/// Runs `parser` over the whole of `input`, allowing only trailing whitespace to be left over,
+/// and turns any failure into a [`ParseError`] pointing at where the parser gave up.
+pub fn finish<'a, T>(
+    input: &'a str,
+    parser: impl Parser<&'a str, T, ErrorTree<&'a str>>,
+) -> Result<T> {
+    match terminated(parser, multispace0).parse(input) {
+        Ok(("", output)) => Ok(output),
+        Ok((rest, _)) => Err(ParseError::at(input, rest, "end of input").into()),
+        Err(nom::Err::Error(tree) | nom::Err::Failure(tree)) => Err(from_tree(input, tree).into()),
+        Err(nom::Err::Incomplete(_)) => Err(ParseError::at(input, "", "more input").into()),
+    }
+}
+
+fn from_tree<'a>(input: &'a str, tree: ErrorTree<&'a str>) -> ParseError {
+    let (tail, expected, mut context) = deepest(tree);
+    // bare nom errors (a failed `verify`, say) are better described by what was being parsed
+    let expected = match expected {
+        Some(expected) => expected,
+        None => context.pop().unwrap_or("valid input").to_string(),
+    };
+    let mut err = ParseError::at(input, tail, expected);
+    err.context = context;
+    err
+}
+
+// walks down to the error that got furthest into the input, collecting the contexts on the way
+fn deepest(tree: ErrorTree<&str>) -> (&str, Option<String>, Vec<&'static str>) {
+    match tree {
+        ErrorTree::Base { location, kind } => {
+            let expected = match kind {
+                BaseErrorKind::Expected(expectation) => Some(expectation.to_string()),
+                BaseErrorKind::Kind(_) => None,
+                BaseErrorKind::External(err) => Some(err.to_string()),
+            };
+            (location, expected, Vec::new())
+        }
+        ErrorTree::Stack { base, contexts } => {
+            let (location, expected, mut inner) = deepest(*base);
+            let mut context = contexts
+                .into_iter()
+                .rev()
+                .filter_map(|(_, context)| match context {
+                    StackContext::Context(context) => Some(context),
+                    StackContext::Kind(_) => None,
+                })
+                .collect::<Vec<_>>();
+            context.append(&mut inner);
+            (location, expected, context)
+        }
+        ErrorTree::Alt(alternatives) => {
+            let alts = alternatives.into_iter().map(deepest).collect::<Vec<_>>();
+            let furthest = alts
+                .iter()
+                .map(|(location, _, _)| location.len())
+                .min()
+                .expect("alt should have at least one branch");
+            let mut alts = alts
+                .into_iter()
+                .filter(|(location, _, _)| location.len() == furthest);
+            let (location, mut expected, context) =
+                alts.next().expect("furthest branch should exist");
+            for (_, other, _) in alts {
+                expected = match (expected, other) {
+                    (Some(expected), Some(other)) => Some(format!("{expected} or {other}")),
+                    (expected, other) => expected.or(other),
+                };
+            }
+            (location, expected, context)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{
+        branch::alt,
+        character::complete::{self, line_ending},
+        multi::separated_list1,
+        sequence::preceded,
+    };
+    use nom_supreme::{tag::complete::tag, ParserExt};
+
+    use crate::Error;
+
+    use super::*;
+
+    fn games(input: &str) -> IResult<'_, Vec<u32>> {
+        separated_list1(
+            line_ending,
+            preceded(tag("Game "), complete::u32.context("game id")),
+        )
+        .context("games")
+        .parse(input)
+    }
+
+    #[test]
+    fn parses_whole_input() {
+        let result = finish("Game 1\nGame 22\n", games).expect("input should parse");
+        assert_eq!(result, vec![1, 22]);
+    }
+
+    #[test]
+    fn reports_line_and_column() {
+        let err = finish("Game 1\nGame x\n", games).expect_err("input should not parse");
+        let Error::Parse(err) = err else {
+            panic!("expected a parse error, got {err:?}");
+        };
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.expected, "end of input");
+    }
+
+    #[test]
+    fn reports_deepest_context() {
+        let err = finish("Game x", games).expect_err("input should not parse");
+        let Error::Parse(err) = err else {
+            panic!("expected a parse error, got {err:?}");
+        };
+        assert_eq!((err.line, err.column), (1, 6));
+        assert_eq!(err.expected, "an ascii digit");
+        assert_eq!(err.context, vec!["games", "game id"]);
+    }
+
+    #[test]
+    fn picks_furthest_alternative() {
+        let mut parser = alt((tag("ab").precedes(tag("c")), tag("x")));
+        let err = finish("abd", |i| parser.parse(i)).expect_err("input should not parse");
+        let message = err.in_file("input/p1.txt").to_string();
+        assert!(message.starts_with("input/p1.txt:1:3: expected \"c\""));
+        assert!(message.contains("1 | abd"));
+    }
+}
@@ -0,0 +1,238 @@
+// the C ABI a day exports when it's built as a `cdylib`, so the runner can load a freshly
+// built day at runtime instead of being rebuilt with it. Everything crossing the boundary is
+// a plain integer, a pointer or a `#[repr(C)]` struct, so the runner and the plugin don't
+// need to agree on a compiler version or on any Rust type layouts
+
+use std::{
+    ffi::{c_char, CString},
+    panic::{self, AssertUnwindSafe},
+    slice, str,
+};
+
+use crate::Solution;
+
+/// Bumped whenever the exported functions or the structs below change shape. The runner
+/// refuses plugins built against a different version.
+pub const ABI_VERSION: u32 = 1;
+
+/// Exported as `aoc_plugin_info() -> PluginInfo`: which puzzle the plugin solves.
+pub const INFO_SYMBOL: &[u8] = b"aoc_plugin_info";
+/// Exported as `aoc_solve(day: u8, part: u8, input: *const u8, len: usize) -> PluginAnswer`.
+pub const SOLVE_SYMBOL: &[u8] = b"aoc_solve";
+/// Exported as `aoc_free_answer(text: *mut c_char)`, which frees an answer's text.
+pub const FREE_SYMBOL: &[u8] = b"aoc_free_answer";
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PluginInfo {
+    pub abi_version: u32,
+    pub year: u16,
+    pub day: u8,
+}
+
+/// The outcome of `aoc_solve`: the answer if `ok`, otherwise the error message. The text is
+/// a nul-terminated UTF-8 string owned by the plugin, and has to be handed back to
+/// `aoc_free_answer` rather than freed by the caller.
+#[repr(C)]
+#[derive(Debug)]
+pub struct PluginAnswer {
+    pub ok: bool,
+    pub text: *mut c_char,
+}
+
+pub type InfoFn = unsafe extern "C" fn() -> PluginInfo;
+pub type SolveFn =
+    unsafe extern "C" fn(day: u8, part: u8, input: *const u8, len: usize) -> PluginAnswer;
+pub type FreeFn = unsafe extern "C" fn(text: *mut c_char);
+
+pub fn info<S: Solution>() -> PluginInfo {
+    PluginInfo {
+        abi_version: ABI_VERSION,
+        year: S::YEAR,
+        day: S::DAY,
+    }
+}
+
+fn answer(ok: bool, text: String) -> PluginAnswer {
+    // a nul in the middle would cut the text short on the other side
+    let text =
+        CString::new(text.replace('\0', "\u{fffd}")).expect("text without nuls should convert");
+    PluginAnswer {
+        ok,
+        text: text.into_raw(),
+    }
+}
+
+fn solve_str<S: Solution>(day: u8, part: u8, input: &[u8]) -> Result<String, String> {
+    if day != S::DAY {
+        return Err(format!("plugin solves day {}, not day {day}", S::DAY));
+    }
+    let input = str::from_utf8(input).map_err(|err| format!("input isn't UTF-8: {err}"))?;
+    let parsed = S::parse(input).map_err(|err| err.to_string())?;
+    match part {
+        1 => S::part1(&parsed).map(|answer| answer.to_string()),
+        2 => S::part2(&parsed).map(|answer| answer.to_string()),
+        _ => return Err(format!("puzzles only have two parts, got part {part}")),
+    }
+    .map_err(|err| err.to_string())
+}
+
+/// Solves one part of `S` for `aoc_solve`, turning errors and panics into a failed answer
+/// since neither can cross the C boundary.
+///
+/// # Safety
+///
+/// `input` must point to `len` readable bytes, or may dangle if `len` is 0.
+pub unsafe fn solve<S: Solution>(day: u8, part: u8, input: *const u8, len: usize) -> PluginAnswer {
+    let input = if len == 0 {
+        &[]
+    } else {
+        // SAFETY: the caller promises `len` readable bytes at `input`
+        unsafe { slice::from_raw_parts(input, len) }
+    };
+    match panic::catch_unwind(AssertUnwindSafe(|| solve_str::<S>(day, part, input))) {
+        Ok(Ok(text)) => answer(true, text),
+        Ok(Err(err)) => answer(false, err),
+        Err(panic) => {
+            let message = panic
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| panic.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            answer(false, format!("panicked: {message}"))
+        }
+    }
+}
+
+/// Frees the text of an answer returned by [`solve`].
+///
+/// # Safety
+///
+/// `text` must have come from [`solve`] in this same library, and not have been freed yet.
+pub unsafe fn free_answer(text: *mut c_char) {
+    if !text.is_null() {
+        // SAFETY: the caller promises this came from `CString::into_raw` in `answer`
+        drop(unsafe { CString::from_raw(text) });
+    }
+}
+
+/// Exports a day's `Solution` through the plugin ABI when the day is built with its `plugin`
+/// feature, e.g. `cargo rustc -p day-05 --lib --release --features plugin --crate-type cdylib`.
+/// The feature keeps the unmangled symbols out of normal builds, where every day is linked
+/// into the runner together and they would clash.
+#[macro_export]
+macro_rules! export_plugin {
+    ($solution:ident) => {
+        #[cfg(feature = "plugin")]
+        mod plugin {
+            use std::ffi::c_char;
+
+            use $crate::plugin::{PluginAnswer, PluginInfo};
+
+            #[no_mangle]
+            pub extern "C" fn aoc_plugin_info() -> PluginInfo {
+                $crate::plugin::info::<super::$solution>()
+            }
+
+            /// # Safety
+            ///
+            /// `input` must point to `len` readable bytes.
+            #[no_mangle]
+            pub unsafe extern "C" fn aoc_solve(
+                day: u8,
+                part: u8,
+                input: *const u8,
+                len: usize,
+            ) -> PluginAnswer {
+                // SAFETY: passed straight through from our own caller
+                unsafe { $crate::plugin::solve::<super::$solution>(day, part, input, len) }
+            }
+
+            /// # Safety
+            ///
+            /// `text` must be an answer's text from `aoc_solve`, freed only once.
+            #[no_mangle]
+            pub unsafe extern "C" fn aoc_free_answer(text: *mut c_char) {
+                // SAFETY: passed straight through from our own caller
+                unsafe { $crate::plugin::free_answer(text) }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CStr;
+
+    use super::*;
+    use crate::{Error, Result};
+
+    struct Doubler;
+
+    impl Solution for Doubler {
+        const YEAR: u16 = 2023;
+        const DAY: u8 = 3;
+
+        type Parsed = u64;
+        type Answer1 = u64;
+        type Answer2 = u64;
+
+        fn parse(input: &str) -> Result<u64> {
+            input
+                .trim()
+                .parse()
+                .map_err(|_| Error::Unsolvable(format!("{input:?} isn't a number")))
+        }
+
+        fn part1(n: &u64) -> Result<u64> {
+            Ok(n * 2)
+        }
+
+        fn part2(n: &u64) -> Result<u64> {
+            n.checked_mul(4).ok_or_else(|| panic!("{n} is too big"))
+        }
+    }
+
+    fn call(day: u8, part: u8, input: &[u8]) -> (bool, String) {
+        // SAFETY: the slice's pointer and length go together, and the text is freed once
+        unsafe {
+            let answer = solve::<Doubler>(day, part, input.as_ptr(), input.len());
+            let text = CStr::from_ptr(answer.text)
+                .to_str()
+                .expect("text should be UTF-8")
+                .to_string();
+            free_answer(answer.text);
+            (answer.ok, text)
+        }
+    }
+
+    #[test]
+    fn answers_and_errors_cross_as_text() {
+        assert_eq!(
+            info::<Doubler>(),
+            PluginInfo {
+                abi_version: ABI_VERSION,
+                year: 2023,
+                day: 3
+            }
+        );
+        assert_eq!(call(3, 1, b"21\n"), (true, "42".to_string()));
+        assert_eq!(call(3, 2, b""), (false, "\"\" isn't a number".to_string()));
+        assert_eq!(
+            call(4, 1, b"21"),
+            (false, "plugin solves day 3, not day 4".to_string())
+        );
+        assert_eq!(
+            call(3, 3, b"21"),
+            (false, "puzzles only have two parts, got part 3".to_string())
+        );
+        assert!(!call(3, 1, b"\xff").0);
+    }
+
+    #[test]
+    fn panics_stay_inside_the_plugin() {
+        let (ok, text) = call(3, 2, u64::MAX.to_string().as_bytes());
+        assert!(!ok);
+        assert_eq!(text, format!("panicked: {} is too big", u64::MAX));
+    }
+}
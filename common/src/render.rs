@@ -0,0 +1,292 @@
+// draws grids as images for debugging: each cell is a square of one colour, with paths and
+// outlined cells drawn over the top, written out as SVG or PNG
+
+use std::fmt::Write;
+
+use crate::{Error, Grid, Pos, Result, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Colour(pub u8, pub u8, pub u8);
+
+impl Colour {
+    pub const BLACK: Colour = Colour(0, 0, 0);
+    pub const WHITE: Colour = Colour(255, 255, 255);
+    pub const GREY: Colour = Colour(128, 128, 128);
+    pub const RED: Colour = Colour(220, 50, 47);
+    pub const GREEN: Colour = Colour(133, 153, 0);
+    pub const BLUE: Colour = Colour(38, 139, 210);
+    pub const YELLOW: Colour = Colour(181, 137, 0);
+
+    fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+/// A day that can draw its parsed input, usually with the solution drawn over it.
+pub trait Render: Solution {
+    fn render(parsed: &Self::Parsed) -> Result<Canvas>;
+}
+
+/// A grid of coloured cells with overlays, ready to be written out as an image.
+#[derive(Debug, Clone)]
+pub struct Canvas {
+    cells: Grid<Colour>,
+    cell_size: usize,
+    highlights: Vec<(Pos, Colour)>,
+    paths: Vec<(Vec<Pos>, Colour)>,
+}
+
+impl Canvas {
+    /// Colours each cell of `grid` with `colour`, drawing every cell 8 pixels wide.
+    pub fn new<T>(grid: &Grid<T>, mut colour: impl FnMut(Pos, &T) -> Colour) -> Self {
+        let rows = grid
+            .rows()
+            .enumerate()
+            .map(|(row, cells)| {
+                cells
+                    .iter()
+                    .enumerate()
+                    .map(|(column, cell)| colour((row, column), cell))
+                    .collect()
+            })
+            .collect::<Vec<Vec<_>>>();
+        Canvas {
+            cells: Grid::from_rows(rows),
+            cell_size: 8,
+            highlights: Vec::new(),
+            paths: Vec::new(),
+        }
+    }
+
+    pub fn with_cell_size(mut self, cell_size: usize) -> Self {
+        assert!(cell_size > 0, "cells should be at least a pixel wide");
+        self.cell_size = cell_size;
+        self
+    }
+
+    /// Outlines a cell.
+    pub fn highlight(&mut self, pos: Pos, colour: Colour) {
+        self.highlights.push((pos, colour));
+    }
+
+    /// Draws a line through the centres of `path`'s cells, in order.
+    pub fn path(&mut self, path: &[Pos], colour: Colour) {
+        self.paths.push((path.to_vec(), colour));
+    }
+
+    /// The image's width and height in pixels.
+    pub fn size(&self) -> (usize, usize) {
+        (
+            self.cells.width() * self.cell_size,
+            self.cells.height() * self.cell_size,
+        )
+    }
+
+    fn line_width(&self) -> usize {
+        (self.cell_size / 4).max(1)
+    }
+
+    // where a cell's centre is, as (x, y) in pixels
+    fn centre(&self, (row, column): Pos) -> (usize, usize) {
+        (
+            column * self.cell_size + self.cell_size / 2,
+            row * self.cell_size + self.cell_size / 2,
+        )
+    }
+
+    pub fn to_svg(&self) -> String {
+        let (width, height) = self.size();
+        let size = self.cell_size;
+        let line = self.line_width();
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+             viewBox=\"0 0 {width} {height}\" shape-rendering=\"crispEdges\">\n"
+        );
+        // writing to a string can't fail
+        for ((row, column), colour) in self.cells.iter() {
+            let (x, y) = (column * size, row * size);
+            let fill = colour.hex();
+            let _ = writeln!(
+                svg,
+                "<rect x=\"{x}\" y=\"{y}\" width=\"{size}\" height=\"{size}\" fill=\"{fill}\"/>"
+            );
+        }
+        for ((row, column), colour) in &self.highlights {
+            // inset by half the stroke so the outline stays inside the cell
+            let offset = line as f64 / 2.0;
+            let (x, y) = (
+                (column * size) as f64 + offset,
+                (row * size) as f64 + offset,
+            );
+            let inner = size as f64 - line as f64;
+            let stroke = colour.hex();
+            let _ = writeln!(
+                svg,
+                "<rect x=\"{x}\" y=\"{y}\" width=\"{inner}\" height=\"{inner}\" fill=\"none\" \
+                 stroke=\"{stroke}\" stroke-width=\"{line}\"/>"
+            );
+        }
+        for (path, colour) in &self.paths {
+            let points = path
+                .iter()
+                .map(|pos| {
+                    let (x, y) = self.centre(*pos);
+                    format!("{x},{y}")
+                })
+                .collect::<Vec<_>>()
+                .join(" ");
+            let stroke = colour.hex();
+            let _ = writeln!(
+                svg,
+                "<polyline points=\"{points}\" fill=\"none\" stroke=\"{stroke}\" \
+                 stroke-width=\"{line}\" stroke-linejoin=\"round\" stroke-linecap=\"round\"/>"
+            );
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// The image as RGB pixels, row by row.
+    pub fn pixels(&self) -> Vec<u8> {
+        let (width, height) = self.size();
+        let mut pixels = vec![0; width * height * 3];
+        let mut fill = |x: usize, y: usize, Colour(r, g, b): Colour| {
+            if x < width && y < height {
+                let at = (y * width + x) * 3;
+                pixels[at..at + 3].copy_from_slice(&[r, g, b]);
+            }
+        };
+        let size = self.cell_size;
+        for ((row, column), colour) in self.cells.iter() {
+            for y in row * size..(row + 1) * size {
+                for x in column * size..(column + 1) * size {
+                    fill(x, y, *colour);
+                }
+            }
+        }
+        let line = self.line_width();
+        for ((row, column), colour) in &self.highlights {
+            for dy in 0..size {
+                for dx in 0..size {
+                    let edge = dx < line || dy < line || dx >= size - line || dy >= size - line;
+                    if edge {
+                        fill(column * size + dx, row * size + dy, *colour);
+                    }
+                }
+            }
+        }
+        for (path, colour) in &self.paths {
+            for segment in path.windows(2) {
+                let (x0, y0) = self.centre(segment[0]);
+                let (x1, y1) = self.centre(segment[1]);
+                // steps one pixel at a time along the longer axis, stamping a square brush
+                let steps = x0.abs_diff(x1).max(y0.abs_diff(y1)).max(1);
+                for step in 0..=steps {
+                    let along = |from: usize, to: usize| {
+                        let (from, to) = (from as f64, to as f64);
+                        (from + (to - from) * step as f64 / steps as f64).round() as usize
+                    };
+                    let (x, y) = (along(x0, x1), along(y0, y1));
+                    for dy in 0..line {
+                        for dx in 0..line {
+                            fill(
+                                (x + dx).saturating_sub(line / 2),
+                                (y + dy).saturating_sub(line / 2),
+                                *colour,
+                            );
+                        }
+                    }
+                }
+            }
+        }
+        pixels
+    }
+
+    /// The image as a PNG, which can't be empty, unlike an SVG.
+    pub fn to_png(&self) -> Result<Vec<u8>> {
+        let (width, height) = self.size();
+        let dimension = |pixels: usize| u32::try_from(pixels).ok().filter(|pixels| *pixels > 0);
+        let (Some(png_width), Some(png_height)) = (dimension(width), dimension(height)) else {
+            return Err(Error::Unsolvable(format!(
+                "a {width}x{height} image can't be written as a PNG"
+            )));
+        };
+        let mut png = Vec::new();
+        let mut encoder = png::Encoder::new(&mut png, png_width, png_height);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        // with the size checked, the only failures are from the writer, and writing to a Vec
+        // can't fail
+        let mut writer = encoder
+            .write_header()
+            .expect("png header should be written");
+        writer
+            .write_image_data(&self.pixels())
+            .expect("png data should be written");
+        writer.finish().expect("png should be finished");
+        Ok(png)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn canvas() -> Canvas {
+        let grid =
+            Grid::parse("#.\n.#\n", "'#' or '.'", |c| Some(c == '#')).expect("grid should parse");
+        Canvas::new(
+            &grid,
+            |_, wall| if *wall { Colour::BLACK } else { Colour::WHITE },
+        )
+        .with_cell_size(4)
+    }
+
+    fn pixel(canvas: &Canvas, x: usize, y: usize) -> Colour {
+        let (width, _) = canvas.size();
+        let pixels = canvas.pixels();
+        let at = (y * width + x) * 3;
+        Colour(pixels[at], pixels[at + 1], pixels[at + 2])
+    }
+
+    #[test]
+    fn draws_cells_and_overlays() {
+        let mut canvas = canvas();
+        assert_eq!(canvas.size(), (8, 8));
+        assert_eq!(pixel(&canvas, 1, 1), Colour::BLACK);
+        assert_eq!(pixel(&canvas, 5, 1), Colour::WHITE);
+
+        canvas.highlight((0, 1), Colour::RED);
+        canvas.path(&[(1, 0), (1, 1)], Colour::BLUE);
+        // the outline is drawn on the cell's edge and leaves its middle alone
+        assert_eq!(pixel(&canvas, 4, 0), Colour::RED);
+        assert_eq!(pixel(&canvas, 6, 2), Colour::WHITE);
+        // the path runs between the centres of the bottom row's cells
+        assert_eq!(pixel(&canvas, 4, 6), Colour::BLUE);
+        assert_eq!(pixel(&canvas, 1, 5), Colour::WHITE);
+    }
+
+    #[test]
+    fn writes_svg_and_png() {
+        let mut canvas = canvas();
+        canvas.path(&[(0, 0), (1, 1)], Colour::BLUE);
+        let svg = canvas.to_svg();
+        assert!(svg.starts_with("<svg "), "{svg}");
+        assert_eq!(svg.matches("<rect ").count(), 4);
+        assert!(svg.contains("points=\"2,2 6,6\""), "{svg}");
+
+        let png = canvas.to_png().expect("a 2x2 grid should make a png");
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+        let decoder = png::Decoder::new(png.as_slice());
+        let reader = decoder.read_info().expect("png should decode");
+        assert_eq!((reader.info().width, reader.info().height), (8, 8));
+    }
+
+    #[test]
+    fn empty_grids_make_no_png() {
+        let grid = Grid::<bool>::from_rows(Vec::new());
+        let canvas = Canvas::new(&grid, |_, _| Colour::BLACK);
+        let err = canvas.to_png().expect_err("a png needs at least one pixel");
+        assert_eq!(err.to_string(), "a 0x0 image can't be written as a PNG");
+    }
+}
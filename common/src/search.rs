@@ -0,0 +1,231 @@
+// graph searches over implicit graphs: nodes are any hashable value and edges come from a
+// successor function, so a grid, a state machine or an explicit adjacency map all work the same
+
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// The fewest steps from `start` to every node reachable from it, `start` included at 0.
+pub fn bfs<N, I>(start: N, mut successors: impl FnMut(&N) -> I) -> HashMap<N, usize>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((node, distance)) = queue.pop_front() {
+        for next in successors(&node) {
+            if let Entry::Vacant(entry) = distances.entry(next.clone()) {
+                entry.insert(distance + 1);
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+    distances
+}
+
+/// A path with the fewest steps from `start` to a node matching `is_goal`, both included.
+pub fn bfs_path<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Vec<N>>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut parents = HashMap::from([(start.clone(), None)]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            return Some(path(&parents, node));
+        }
+        for next in successors(&node) {
+            if let Entry::Vacant(entry) = parents.entry(next.clone()) {
+                entry.insert(Some(node.clone()));
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+/// Every node reachable from `start` in depth-first order, each visited once.
+pub fn dfs<N, I>(start: N, mut successors: impl FnMut(&N) -> I) -> Vec<N>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen = HashSet::new();
+    let mut order = Vec::new();
+    let mut stack = vec![start];
+    while let Some(node) = stack.pop() {
+        if !seen.insert(node.clone()) {
+            continue;
+        }
+        // pushed in reverse so the first successor is the first one explored
+        let next = successors(&node)
+            .into_iter()
+            .filter(|next| !seen.contains(next))
+            .collect::<Vec<_>>();
+        stack.extend(next.into_iter().rev());
+        order.push(node);
+    }
+    order
+}
+
+/// The cheapest path from `start` to a node matching `is_goal` and its cost, where
+/// `successors` gives each neighbour with the cost of moving to it.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Ord + Copy + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], guided by `heuristic`, an estimate of the cost left to reach a goal.
+/// The path is only guaranteed to be the cheapest when the estimate is never too high.
+pub fn astar<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Ord + Copy + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut costs = HashMap::from([(start.clone(), C::default())]);
+    let mut parents = HashMap::from([(start.clone(), None)]);
+    // nodes aren't `Ord`, so the heap holds indexes into `nodes`
+    let mut nodes = vec![start.clone()];
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        let node = nodes[index].clone();
+        // a stale entry for a node since reached more cheaply
+        if costs[&node] < cost {
+            continue;
+        }
+        if is_goal(&node) {
+            return Some((path(&parents, node), cost));
+        }
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            if costs.get(&next).is_some_and(|known| *known <= next_cost) {
+                continue;
+            }
+            costs.insert(next.clone(), next_cost);
+            parents.insert(next.clone(), Some(node.clone()));
+            heap.push(Reverse((
+                next_cost + heuristic(&next),
+                next_cost,
+                nodes.len(),
+            )));
+            nodes.push(next);
+        }
+    }
+    None
+}
+
+// follows `parents` back from `end` to the node without one
+fn path<N: Eq + Hash + Clone>(parents: &HashMap<N, Option<N>>, end: N) -> Vec<N> {
+    let mut path = vec![end];
+    while let Some(Some(parent)) = parents.get(path.last().expect("path is never empty")) {
+        path.push(parent.clone());
+    }
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Grid;
+
+    use super::*;
+
+    const MAZE: &str = "\
+S.#.....
+.##.###.
+....#..E
+.####.#.
+......#.
+";
+
+    fn maze() -> Grid<char> {
+        Grid::parse(MAZE, "a maze tile", Some).expect("maze should parse")
+    }
+
+    #[test]
+    fn bfs_finds_shortest_paths() {
+        let maze = maze();
+        let open = |pos: &(usize, usize)| {
+            maze.neighbours4(*pos)
+                .filter(|next| maze[*next] != '#')
+                .collect::<Vec<_>>()
+        };
+        let distances = bfs((0, 0), open);
+        assert_eq!(distances[&(0, 0)], 0);
+        assert_eq!(distances[&(2, 7)], 13);
+        assert!(!distances.contains_key(&(0, 2)));
+
+        let path = bfs_path((0, 0), open, |pos| maze[*pos] == 'E').expect("exit is reachable");
+        assert_eq!(path.len(), 14);
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&(2, 7)));
+        assert!(path
+            .windows(2)
+            .all(|step| maze.neighbours4(step[0]).any(|n| n == step[1])));
+        assert_eq!(bfs_path((0, 0), open, |pos| *pos == (0, 2)), None);
+    }
+
+    #[test]
+    fn dfs_visits_each_node_once() {
+        let edges = HashMap::from([(1, vec![2, 3]), (2, vec![4]), (3, vec![4]), (4, vec![1])]);
+        let order = dfs(1, |node| edges[node].clone());
+        assert_eq!(order, vec![1, 2, 4, 3]);
+    }
+
+    #[test]
+    fn dijkstra_and_astar_find_the_cheapest_path() {
+        // going through b is more steps but cheaper
+        let edges = HashMap::from([
+            ('a', vec![('b', 1), ('d', 10)]),
+            ('b', vec![('c', 2)]),
+            ('c', vec![('d', 3)]),
+            ('d', vec![]),
+        ]);
+        let successors = |node: &char| edges[node].clone();
+        assert_eq!(
+            dijkstra('a', successors, |node| *node == 'd'),
+            Some((vec!['a', 'b', 'c', 'd'], 6))
+        );
+        assert_eq!(dijkstra('d', successors, |node| *node == 'a'), None);
+
+        let maze = maze();
+        let exit = (2, 7);
+        let (path, cost) = astar(
+            (0, 0),
+            |pos: &(usize, usize)| {
+                maze.neighbours4(*pos)
+                    .filter(|next| maze[*next] != '#')
+                    .map(|next| (next, 1))
+                    .collect::<Vec<_>>()
+            },
+            |pos| pos.0.abs_diff(exit.0) + pos.1.abs_diff(exit.1),
+            |pos| *pos == exit,
+        )
+        .expect("exit is reachable");
+        assert_eq!(cost, 13);
+        assert_eq!(path.len(), 14);
+    }
+}
@@ -0,0 +1,123 @@
+use std::{
+    env,
+    ffi::OsString,
+    fmt::{Debug, Display},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::Instant,
+};
+
+use crate::{read_input, Format, Report, Result};
+
+/// A day's puzzle: one parser shared by both parts, so the input is only parsed once
+/// when both parts are run.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    type Parsed;
+    type Answer1: Debug + Display;
+    type Answer2: Debug + Display;
+
+    fn parse(input: &str) -> Result<Self::Parsed>;
+    fn part1(parsed: &Self::Parsed) -> Result<Self::Answer1>;
+    fn part2(parsed: &Self::Parsed) -> Result<Self::Answer2>;
+
+    /// Reads and parses the input at `path`, naming the file in any parse error.
+    fn parse_file(path: impl AsRef<Path>) -> Result<Self::Parsed> {
+        let path = path.as_ref();
+        Self::parse(&read_input(path)?).map_err(|err| err.in_file(path))
+    }
+}
+
+// a binary's `--format` and input path, the input defaulting to the day's own
+fn bin_args(
+    args: impl IntoIterator<Item = OsString>,
+) -> std::result::Result<(Format, PathBuf), String> {
+    let mut args = args.into_iter();
+    let mut format = Format::default();
+    let mut path = None;
+    while let Some(arg) = args.next() {
+        if arg == "--format" {
+            let value = args.next().ok_or("--format needs a value")?;
+            format = value.to_string_lossy().parse()?;
+        } else if path.is_none() {
+            path = Some(PathBuf::from(arg));
+        } else {
+            return Err(format!("unexpected argument {:?}", arg.to_string_lossy()));
+        }
+    }
+    Ok((format, path.unwrap_or_else(|| "input/p1.txt".into())))
+}
+
+fn solve<S: Solution>(path: &Path, part: u8) -> Result<Report> {
+    let input = read_input(path)?;
+    let start = Instant::now();
+    let parsed = S::parse(&input).map_err(|err| err.in_file(path))?;
+    let parse = start.elapsed();
+    let start = Instant::now();
+    let answer = match part {
+        1 => S::part1(&parsed)?.to_string(),
+        2 => S::part2(&parsed)?.to_string(),
+        _ => panic!("puzzles only have two parts, got part {part}"),
+    };
+    Ok(Report {
+        year: S::YEAR,
+        day: S::DAY,
+        part,
+        answer,
+        parse: Some(parse),
+        solve: start.elapsed(),
+    })
+}
+
+/// The `main` of every `partN` binary: solves one part against the input path given as its
+/// argument, `-` for stdin, or the day's `input/p1.txt` by default, and prints the answer in
+/// the `--format` asked for. Failures are printed as a diagnostic rather than panicking.
+pub fn run_bin<S: Solution>(part: u8) -> ExitCode {
+    let mut args = env::args_os();
+    let bin = args.next().unwrap_or_default();
+    let (format, path) = match bin_args(args) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("error: {err}");
+            eprintln!(
+                "usage: {} [--format plain|human|json] [INPUT | -]",
+                bin.to_string_lossy()
+            );
+            return ExitCode::FAILURE;
+        }
+    };
+    match solve::<S>(&path, part) {
+        Ok(report) => {
+            println!("{}", report.render(format));
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bin_args_pick_format_and_input() {
+        let args = |args: &[&str]| bin_args(args.iter().map(OsString::from));
+        assert_eq!(
+            args(&[]),
+            Ok((Format::Plain, PathBuf::from("input/p1.txt")))
+        );
+        assert_eq!(args(&["-"]), Ok((Format::Plain, PathBuf::from("-"))));
+        assert_eq!(
+            args(&["--format", "json", "other.txt"]),
+            Ok((Format::Json, PathBuf::from("other.txt")))
+        );
+        assert!(args(&["--format"]).is_err());
+        assert!(args(&["--format", "yaml"]).is_err());
+        assert!(args(&["a.txt", "b.txt"]).is_err());
+    }
+}
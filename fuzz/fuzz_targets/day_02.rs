@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_common::Solution;
+use day_02::Day02;
+use libfuzzer_sys::fuzz_target;
+
+// any input has to parse or fail with an error, never panic
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        let _ = Day02::parse(input);
+    }
+});
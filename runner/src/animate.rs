@@ -0,0 +1,242 @@
+use std::{
+    any::Any,
+    ops::ControlFlow,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, bail, Result};
+use aoc_common::{read_input, Colour, Frame};
+use clap::Args;
+use ratatui::{
+    crossterm::event::{self, Event, KeyCode, KeyEventKind},
+    layout::{Constraint, Layout},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    DefaultTerminal,
+};
+
+use crate::registry::{self, AnimateFn};
+
+#[derive(Args)]
+pub struct AnimateArgs {
+    /// Puzzle year, defaults to the most recent year with solutions
+    #[arg(long)]
+    year: Option<u16>,
+    /// Puzzle day, 1 to 25
+    #[arg(long)]
+    day: u8,
+    /// Puzzle input to use instead of the day's `input/p1.txt`, or `-` for stdin
+    #[arg(long)]
+    input: Option<PathBuf>,
+    /// Frames per second to start at
+    #[arg(
+        long,
+        default_value_t = 20,
+        value_parser = clap::value_parser!(u32).range(1..=i64::from(MAX_FPS))
+    )]
+    fps: u32,
+}
+
+const MAX_FPS: u32 = 1000;
+const HELP: &str = "space pause, n step, +/- speed, arrows scroll, q quit";
+
+#[derive(Debug, PartialEq, Eq)]
+struct Controls {
+    paused: bool,
+    fps: u32,
+    // rows and columns scrolled past, for grids bigger than the terminal
+    scroll: (u16, u16),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Action {
+    Wait,
+    Next,
+    Quit,
+}
+
+impl Controls {
+    fn new(fps: u32) -> Self {
+        Controls {
+            paused: false,
+            fps,
+            scroll: (0, 0),
+        }
+    }
+
+    fn delay(&self) -> Duration {
+        Duration::from_secs(1) / self.fps
+    }
+
+    fn press(&mut self, key: KeyCode) -> Action {
+        match key {
+            KeyCode::Char('q') | KeyCode::Esc => return Action::Quit,
+            KeyCode::Char('n') | KeyCode::Char('.') => return Action::Next,
+            KeyCode::Char(' ') => self.paused = !self.paused,
+            KeyCode::Char('+') | KeyCode::Char('=') => self.fps = (self.fps * 2).min(MAX_FPS),
+            KeyCode::Char('-') => self.fps = (self.fps / 2).max(1),
+            KeyCode::Up => self.scroll.0 = self.scroll.0.saturating_sub(1),
+            KeyCode::Down => self.scroll.0 = self.scroll.0.saturating_add(1),
+            KeyCode::Left => self.scroll.1 = self.scroll.1.saturating_sub(1),
+            KeyCode::Right => self.scroll.1 = self.scroll.1.saturating_add(1),
+            _ => {}
+        }
+        Action::Wait
+    }
+}
+
+fn colour(Colour(r, g, b): Colour) -> Color {
+    Color::Rgb(r, g, b)
+}
+
+fn draw(
+    terminal: &mut DefaultTerminal,
+    frame: &Frame,
+    status: &str,
+    scroll: (u16, u16),
+) -> Result<()> {
+    let lines = frame
+        .cells
+        .rows()
+        .map(|row| {
+            row.iter()
+                .map(|cell| {
+                    Span::styled(
+                        cell.symbol.to_string(),
+                        Style::new().fg(colour(cell.colour)),
+                    )
+                })
+                .collect::<Line>()
+        })
+        .collect::<Vec<_>>();
+    let footer = vec![Line::from(frame.caption.as_str()), Line::from(status)];
+    terminal.draw(|screen| {
+        let [grid, footer_area] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(2)]).areas(screen.area());
+        screen.render_widget(Paragraph::new(lines).scroll(scroll), grid);
+        screen.render_widget(Paragraph::new(footer), footer_area);
+    })?;
+    Ok(())
+}
+
+// shows one frame until it's time for the next, or for the last frame until the player quits
+fn show(
+    terminal: &mut DefaultTerminal,
+    controls: &mut Controls,
+    frame: &Frame,
+    number: usize,
+    last: bool,
+) -> Result<ControlFlow<()>> {
+    let mut deadline = Instant::now() + controls.delay();
+    loop {
+        let state = match (last, controls.paused) {
+            (true, _) => "finished".to_string(),
+            (false, true) => "paused".to_string(),
+            (false, false) => format!("{} fps", controls.fps),
+        };
+        let status = format!("frame {number} | {state} | {HELP}");
+        draw(terminal, frame, &status, controls.scroll)?;
+        let waiting = last || controls.paused;
+        let timeout = deadline.saturating_duration_since(Instant::now());
+        if !waiting && !event::poll(timeout)? {
+            return Ok(ControlFlow::Continue(()));
+        }
+        if let Event::Key(key) = event::read()? {
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            let was_paused = controls.paused;
+            match controls.press(key.code) {
+                Action::Quit => return Ok(ControlFlow::Break(())),
+                Action::Next if !last => return Ok(ControlFlow::Continue(())),
+                _ => {}
+            }
+            // resuming shouldn't count the time spent paused
+            if was_paused && !controls.paused {
+                deadline = Instant::now() + controls.delay();
+            }
+        }
+    }
+}
+
+fn play(
+    terminal: &mut DefaultTerminal,
+    animate: AnimateFn,
+    parsed: &dyn Any,
+    fps: u32,
+) -> Result<()> {
+    let mut controls = Controls::new(fps);
+    let mut shown = 0;
+    let mut last = None;
+    let mut quit = false;
+    let mut failure = None;
+    let played = animate(parsed, &mut |frame| {
+        shown += 1;
+        let flow = show(terminal, &mut controls, &frame, shown, false);
+        last = Some(frame);
+        match flow {
+            Ok(flow) => {
+                quit = flow.is_break();
+                flow
+            }
+            Err(err) => {
+                failure = Some(err);
+                ControlFlow::Break(())
+            }
+        }
+    });
+    if let Some(err) = failure {
+        return Err(err);
+    }
+    played?;
+    // the last frame stays up until the player quits, unless they already have
+    if let (false, Some(frame)) = (quit, last) {
+        let _ = show(terminal, &mut controls, &frame, shown, true)?;
+    }
+    Ok(())
+}
+
+pub fn run(args: AnimateArgs) -> Result<()> {
+    let solver = registry::select(false, args.year, Some(args.day))?[0];
+    let label = format!("{} day {:02}", solver.year, solver.day);
+    let Some(animate) = solver.animate else {
+        bail!("{label} can't be animated");
+    };
+    let path = args.input.unwrap_or_else(|| solver.default_input());
+    let input = read_input(&path).map_err(|err| anyhow!("{label}: {err}"))?;
+    let parsed =
+        (solver.parse)(&input).map_err(|err| anyhow!("{label}: {}", err.in_file(&path)))?;
+
+    // restores the terminal on a panic too
+    let mut terminal = ratatui::init();
+    let played = play(&mut terminal, animate, parsed.as_ref(), args.fps);
+    ratatui::restore();
+    played.map_err(|err| anyhow!("{label}: {err}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keys_control_playback() {
+        let mut controls = Controls::new(20);
+        assert_eq!(controls.delay(), Duration::from_millis(50));
+        assert_eq!(controls.press(KeyCode::Char(' ')), Action::Wait);
+        assert!(controls.paused);
+        assert_eq!(controls.press(KeyCode::Char('n')), Action::Next);
+        assert!(controls.paused, "stepping should stay paused");
+        controls.press(KeyCode::Char('+'));
+        assert_eq!(controls.fps, 40);
+        for _ in 0..10 {
+            controls.press(KeyCode::Char('-'));
+        }
+        assert_eq!(controls.fps, 1);
+        controls.press(KeyCode::Up);
+        controls.press(KeyCode::Right);
+        assert_eq!(controls.scroll, (0, 1));
+        assert_eq!(controls.press(KeyCode::Esc), Action::Quit);
+    }
+}
@@ -0,0 +1,91 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::registry::workspace_root;
+
+const HEADER: &str = "\
+# Accepted answers for our puzzle inputs, checked by `aoc verify`.
+# `aoc submit` adds an entry whenever an answer is accepted; entries can also be added by hand.
+";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KnownAnswer {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(default, rename = "answer")]
+    pub answers: Vec<KnownAnswer>,
+}
+
+impl Answers {
+    pub fn path() -> PathBuf {
+        workspace_root().join("answers.toml")
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Answers::default());
+        }
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("unable to read {}", path.display()))?;
+        toml::from_str(&contents).with_context(|| format!("invalid answers {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let contents = format!("{HEADER}\n{}", toml::to_string(self)?);
+        fs::write(path, contents).with_context(|| format!("unable to write {}", path.display()))
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: u8) -> Option<&str> {
+        self.answers
+            .iter()
+            .find(|a| (a.year, a.day, a.part) == (year, day, part))
+            .map(|a| a.answer.as_str())
+    }
+
+    // keeps the file in calendar order so hand edits and automatic ones diff cleanly
+    pub fn set(&mut self, year: u16, day: u8, part: u8, answer: &str) {
+        self.answers
+            .retain(|a| (a.year, a.day, a.part) != (year, day, part));
+        self.answers.push(KnownAnswer {
+            year,
+            day,
+            part,
+            answer: answer.to_string(),
+        });
+        self.answers.sort_by_key(|a| (a.year, a.day, a.part));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_replaces_and_sorts() {
+        let mut answers = Answers::default();
+        answers.set(2023, 12, 1, "21");
+        answers.set(2023, 5, 2, "47");
+        answers.set(2023, 5, 2, "46");
+        assert_eq!(answers.get(2023, 5, 2), Some("46"));
+        assert_eq!(answers.get(2023, 5, 1), None);
+        let days = answers.answers.iter().map(|a| a.day).collect::<Vec<_>>();
+        assert_eq!(days, vec![5, 12]);
+
+        let dir = tempfile::tempdir().expect("temp dir should be created");
+        let path = dir.path().join("answers.toml");
+        answers.save(&path).expect("answers should save");
+        let loaded = Answers::load(&path).expect("answers should load");
+        assert_eq!(loaded.answers, answers.answers);
+    }
+}
@@ -0,0 +1,270 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use anyhow::{bail, Context, Result};
+use aoc_common::{read_input, Error};
+use clap::Args;
+use serde::{Deserialize, Serialize};
+
+use crate::registry::{self, Solver};
+
+#[derive(Args)]
+pub struct BenchArgs {
+    /// Benchmark every registered day
+    #[arg(long, conflicts_with_all = ["year", "day"])]
+    all: bool,
+    /// Puzzle year, defaults to the most recent year with solutions
+    #[arg(long)]
+    year: Option<u16>,
+    /// Puzzle day, 1 to 25
+    #[arg(long, required_unless_present = "all")]
+    day: Option<u8>,
+    /// Only benchmark this part, otherwise both parts are benchmarked
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// How many times to run each phase
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    runs: u32,
+    /// Use each day's `input/p1.example.txt` instead of `input/p1.txt`
+    #[arg(long)]
+    example: bool,
+    /// Write the results to this JSON file
+    #[arg(long)]
+    save: Option<PathBuf>,
+    /// Compare against results previously written with `--save`
+    #[arg(long)]
+    baseline: Option<PathBuf>,
+    /// Flag phases whose median is this many percent slower than the baseline
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+}
+
+// timings for one phase (parse, part1 or part2) of one day, in nanoseconds
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Measurement {
+    pub year: u16,
+    pub day: u8,
+    pub phase: String,
+    pub runs: u32,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Measurement {
+    fn new(solver: &Solver, phase: &str, mut times: Vec<Duration>) -> Self {
+        times.sort();
+        let nanos = |time: Duration| u64::try_from(time.as_nanos()).expect("should fit in u64");
+        let mid = times.len() / 2;
+        let median = if times.len().is_multiple_of(2) {
+            (times[mid - 1] + times[mid]) / 2
+        } else {
+            times[mid]
+        };
+        Measurement {
+            year: solver.year,
+            day: solver.day,
+            phase: phase.to_string(),
+            runs: times.len().try_into().expect("should fit in u32"),
+            min_ns: nanos(times[0]),
+            median_ns: nanos(median),
+            max_ns: nanos(times[times.len() - 1]),
+        }
+    }
+
+    fn same_phase(&self, other: &Measurement) -> bool {
+        self.year == other.year && self.day == other.day && self.phase == other.phase
+    }
+}
+
+// runs `f` `runs` times, returning how long each run took and the output of the last one
+fn time<T>(
+    runs: u32,
+    mut f: impl FnMut() -> aoc_common::Result<T>,
+) -> aoc_common::Result<(Vec<Duration>, T)> {
+    let mut times = Vec::new();
+    let mut output = None;
+    for _ in 0..runs {
+        let start = Instant::now();
+        let result = f()?;
+        times.push(start.elapsed());
+        output = Some(result);
+    }
+    Ok((times, output.expect("should run at least once")))
+}
+
+fn bench_day(
+    solver: &Solver,
+    input: &str,
+    parts: &[u8],
+    runs: u32,
+) -> aoc_common::Result<Vec<Measurement>> {
+    let (times, parsed) = time(runs, || (solver.parse)(input))?;
+    let mut measurements = vec![Measurement::new(solver, "parse", times)];
+    for part in parts {
+        let (times, _) = time(runs, || solver.part(*part)(parsed.as_ref()))?;
+        measurements.push(Measurement::new(solver, &format!("part{part}"), times));
+    }
+    Ok(measurements)
+}
+
+// how much slower (positive) or faster (negative) the median got, as a percentage
+fn change(current: &Measurement, baseline: &[Measurement]) -> Option<f64> {
+    let previous = baseline.iter().find(|b| b.same_phase(current))?;
+    if previous.median_ns == 0 {
+        return None;
+    }
+    Some((current.median_ns as f64 / previous.median_ns as f64 - 1.0) * 100.0)
+}
+
+fn load(path: &Path) -> Result<Vec<Measurement>> {
+    let json = fs::read_to_string(path)
+        .with_context(|| format!("unable to read baseline {}", path.display()))?;
+    serde_json::from_str(&json)
+        .with_context(|| format!("{} is not a saved benchmark", path.display()))
+}
+
+fn print_table(measurements: &[Measurement], baseline: &[Measurement], threshold: f64) -> usize {
+    let fmt = |ns: u64| format!("{:.1?}", Duration::from_nanos(ns));
+    println!(
+        "{:<10} {:<6} {:>5} {:>12} {:>12} {:>12}{}",
+        "day",
+        "phase",
+        "runs",
+        "min",
+        "median",
+        "max",
+        if baseline.is_empty() {
+            ""
+        } else {
+            "   vs baseline"
+        }
+    );
+    let mut regressions = 0;
+    for m in measurements {
+        let comparison = match change(m, baseline) {
+            Some(pct) if pct > threshold => {
+                regressions += 1;
+                format!("   {pct:+.1}% REGRESSION")
+            }
+            Some(pct) => format!("   {pct:+.1}%"),
+            None if baseline.is_empty() => String::new(),
+            None => "   new".to_string(),
+        };
+        println!(
+            "{:<10} {:<6} {:>5} {:>12} {:>12} {:>12}{comparison}",
+            format!("{} {:02}", m.year, m.day),
+            m.phase,
+            m.runs,
+            fmt(m.min_ns),
+            fmt(m.median_ns),
+            fmt(m.max_ns),
+        );
+    }
+    regressions
+}
+
+pub fn run(args: BenchArgs) -> Result<()> {
+    let solvers = registry::select(args.all, args.year, args.day)?;
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let baseline = match &args.baseline {
+        Some(path) => load(path)?,
+        None => Vec::new(),
+    };
+    let mut measurements = Vec::new();
+    let mut failures = 0;
+    for solver in solvers {
+        let path = if args.example {
+            solver.example_input()
+        } else {
+            solver.default_input()
+        };
+        let input = match read_input(&path) {
+            Ok(input) => input,
+            // a missing input only means the day hasn't been downloaded yet, so it isn't a failure
+            Err(Error::Io { source, .. }) if source.kind() == io::ErrorKind::NotFound => {
+                eprintln!(
+                    "{} day {:02}: skipped, no input at {}",
+                    solver.year,
+                    solver.day,
+                    path.display()
+                );
+                continue;
+            }
+            Err(err) => {
+                eprintln!("{} day {:02}: {err}", solver.year, solver.day);
+                failures += 1;
+                continue;
+            }
+        };
+        match bench_day(solver, &input, &parts, args.runs) {
+            Ok(day) => measurements.extend(day),
+            Err(err) => {
+                eprintln!(
+                    "{} day {:02}: {}",
+                    solver.year,
+                    solver.day,
+                    err.in_file(&path)
+                );
+                failures += 1;
+            }
+        }
+    }
+    let regressions = print_table(&measurements, &baseline, args.threshold);
+    if let Some(path) = &args.save {
+        let json = serde_json::to_string_pretty(&measurements)?;
+        fs::write(path, json).with_context(|| format!("unable to write {}", path.display()))?;
+    }
+    if failures > 0 {
+        bail!("{failures} day(s) could not be benchmarked");
+    }
+    if regressions > 0 {
+        bail!(
+            "{regressions} phase(s) regressed by more than {}%",
+            args.threshold
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement(phase: &str, median_ns: u64) -> Measurement {
+        Measurement {
+            year: 2023,
+            day: 5,
+            phase: phase.to_string(),
+            runs: 3,
+            min_ns: median_ns,
+            median_ns,
+            max_ns: median_ns,
+        }
+    }
+
+    #[test]
+    fn summarises_runs() {
+        let solver = registry::find(2023, 5).expect("day 5 should be registered");
+        let times = [40, 10, 30, 20].map(Duration::from_nanos).to_vec();
+        let m = Measurement::new(solver, "parse", times);
+        assert_eq!((m.runs, m.min_ns, m.median_ns, m.max_ns), (4, 10, 25, 40));
+    }
+
+    #[test]
+    fn compares_against_baseline() {
+        let baseline = vec![measurement("parse", 100), measurement("part1", 100)];
+        assert_eq!(change(&measurement("parse", 150), &baseline), Some(50.0));
+        assert_eq!(change(&measurement("part1", 50), &baseline), Some(-50.0));
+        assert_eq!(change(&measurement("part2", 50), &baseline), None);
+        let json = serde_json::to_string(&baseline).expect("measurements should serialize");
+        let loaded: Vec<Measurement> = serde_json::from_str(&json).expect("json should load");
+        assert_eq!(loaded, baseline);
+    }
+}
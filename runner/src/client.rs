@@ -0,0 +1,75 @@
+use std::time::Duration;
+
+use anyhow::{anyhow, Context, Result};
+use ureq::{Agent, AgentBuilder};
+
+use crate::config::Config;
+
+const USER_AGENT: &str = concat!("aoc-runner/", env!("CARGO_PKG_VERSION"));
+
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    session: Option<String>,
+}
+
+impl Client {
+    pub fn new(config: &Config) -> Self {
+        Client {
+            agent: AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+            base_url: config.base_url().to_string(),
+            session: config.session.clone(),
+        }
+    }
+
+    // the session is only needed once something is actually requested, so commands that find
+    // everything cached work without one
+    fn cookie(&self) -> Result<String> {
+        let session = self.session.as_ref().ok_or_else(|| {
+            anyhow!("no session token, set AOC_SESSION or `session` in .aoc.toml")
+        })?;
+        Ok(format!("session={session}"))
+    }
+
+    fn day_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{year}/day/{day}", self.base_url)
+    }
+
+    fn get(&self, url: &str) -> Result<String> {
+        let response = self
+            .agent
+            .get(url)
+            .set("Cookie", &self.cookie()?)
+            .call()
+            .with_context(|| format!("unable to fetch {url}"))?;
+        response
+            .into_string()
+            .with_context(|| format!("unable to read the response from {url}"))
+    }
+
+    pub fn input(&self, year: u16, day: u8) -> Result<String> {
+        self.get(&format!("{}/input", self.day_url(year, day)))
+    }
+
+    // the puzzle description, which only includes part 2 once part 1 is solved
+    pub fn puzzle(&self, year: u16, day: u8) -> Result<String> {
+        self.get(&self.day_url(year, day))
+    }
+
+    // returns the page the site answers with, which says whether the answer was right
+    pub fn submit(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<String> {
+        let url = format!("{}/answer", self.day_url(year, day));
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie()?)
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .with_context(|| format!("unable to submit to {url}"))?;
+        response
+            .into_string()
+            .with_context(|| format!("unable to read the response from {url}"))
+    }
+}
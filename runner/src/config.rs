@@ -0,0 +1,53 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::registry::workspace_root;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+// settings for talking to the puzzle site, read from `.aoc.toml` at the workspace root (or
+// wherever `AOC_CONFIG` points), with `AOC_SESSION` and `AOC_BASE_URL` taking precedence
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+}
+
+impl Config {
+    pub fn load() -> Result<Self> {
+        let path = env::var_os("AOC_CONFIG")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| workspace_root().join(".aoc.toml"));
+        let mut config = if path.exists() {
+            Self::from_file(&path)?
+        } else {
+            Config::default()
+        };
+        if let Ok(session) = env::var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = env::var("AOC_BASE_URL") {
+            config.base_url = Some(base_url);
+        }
+        Ok(config)
+    }
+
+    fn from_file(path: &Path) -> Result<Self> {
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("unable to read config {}", path.display()))?;
+        toml::from_str(&contents).with_context(|| format!("invalid config {}", path.display()))
+    }
+
+    pub fn base_url(&self) -> &str {
+        self.base_url
+            .as_deref()
+            .unwrap_or(DEFAULT_BASE_URL)
+            .trim_end_matches('/')
+    }
+}
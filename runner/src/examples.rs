@@ -0,0 +1,258 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Result};
+use aoc_common::ExampleAnswers;
+use clap::Args;
+
+use crate::registry;
+
+#[derive(Args)]
+pub struct ExamplesArgs {
+    /// Puzzle year, defaults to the most recent year with solutions
+    #[arg(long)]
+    year: Option<u16>,
+    /// Puzzle day, 1 to 25
+    #[arg(long)]
+    day: u8,
+    /// Puzzle page saved from the browser, once part 1 is solved it includes part 2 as well
+    page: PathBuf,
+    /// Replace examples that already exist
+    #[arg(long)]
+    force: bool,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Example {
+    // `p1.example`, `p1.example2`, ... for the examples in the part 1 description, and the same
+    // with `p2` for part 2
+    pub name: String,
+    pub input: String,
+    pub answers: ExampleAnswers,
+}
+
+impl Example {
+    pub fn input_path(&self, dir: &Path) -> PathBuf {
+        dir.join(format!("{}.txt", self.name))
+    }
+
+    pub fn answers_path(&self, dir: &Path) -> PathBuf {
+        ExampleAnswers::path(self.input_path(dir))
+    }
+}
+
+// every `<pre><code>` block in the page, with the emphasised answer at the end of each part's
+// description given to the example just before it, which for a part 2 without examples of its
+// own is the last one from part 1
+pub fn extract(page: &str) -> Vec<Example> {
+    let articles = elements(page, "<article class=\"day-desc\">", "</article>");
+    let mut examples = Vec::<Example>::new();
+    for (part, article) in (1..=2).zip(articles) {
+        for (n, block) in elements(article, "<pre><code>", "</code></pre>").enumerate() {
+            let name = match n {
+                0 => format!("p{part}.example"),
+                n => format!("p{part}.example{}", n + 1),
+            };
+            examples.push(Example {
+                name,
+                input: text(block),
+                answers: ExampleAnswers::default(),
+            });
+        }
+        if let (Some(answer), Some(example)) = (last_answer(article), examples.last_mut()) {
+            example.answers.set(part, answer);
+        }
+    }
+    examples
+}
+
+// the contents of each `open`...`close` element, which is enough for the site's plain markup
+fn elements<'a>(html: &'a str, open: &'a str, close: &'a str) -> impl Iterator<Item = &'a str> {
+    let mut rest = html;
+    std::iter::from_fn(move || {
+        let (_, after) = rest.split_once(open)?;
+        let (inner, after) = after.split_once(close)?;
+        rest = after;
+        Some(inner)
+    })
+}
+
+// answers are emphasised code, written either way round; examples are skipped since their
+// lines can be emphasised too
+fn last_answer(article: &str) -> Option<String> {
+    let prose = article
+        .split("<pre>")
+        .map(|piece| piece.split_once("</pre>").map_or(piece, |(_, after)| after))
+        .collect::<String>();
+    ["<code><em>", "<em><code>"]
+        .into_iter()
+        .filter_map(|open| {
+            let start = prose.rfind(open)?;
+            let inner = &prose[start + open.len()..];
+            let end = inner.find("</")?;
+            Some((start, text(&inner[..end])))
+        })
+        .max_by_key(|(start, _)| *start)
+        .map(|(_, answer)| answer)
+}
+
+// strips tags and decodes the entities the site escapes
+fn text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+// writes the example and its answers, unless it already exists and `force` isn't set
+pub fn write_example(dir: &Path, example: &Example, force: bool) -> Result<bool> {
+    let input_path = example.input_path(dir);
+    if input_path.exists() && !force {
+        return Ok(false);
+    }
+    fs::create_dir_all(dir).with_context(|| format!("unable to create {}", dir.display()))?;
+    fs::write(&input_path, &example.input)
+        .with_context(|| format!("unable to write {}", input_path.display()))?;
+    let answers_path = example.answers_path(dir);
+    if example.answers.is_empty() {
+        // an answer from an earlier extraction would no longer match the example
+        if answers_path.exists() {
+            fs::remove_file(&answers_path)
+                .with_context(|| format!("unable to remove {}", answers_path.display()))?;
+        }
+    } else {
+        fs::write(&answers_path, toml::to_string(&example.answers)?)
+            .with_context(|| format!("unable to write {}", answers_path.display()))?;
+    }
+    Ok(true)
+}
+
+pub fn run(args: ExamplesArgs) -> Result<()> {
+    let year = args.year.unwrap_or_else(registry::latest_year);
+    let page = fs::read_to_string(&args.page)
+        .with_context(|| format!("unable to read {}", args.page.display()))?;
+    let examples = extract(&page);
+    if examples.is_empty() {
+        bail!("no examples found in {}", args.page.display());
+    }
+    let dir = registry::day_dir(year, args.day).join("input");
+    for example in &examples {
+        let path = example.input_path(&dir);
+        if !write_example(&dir, example, args.force)? {
+            println!("{}: exists, kept", path.display());
+            continue;
+        }
+        let answers = [1, 2]
+            .into_iter()
+            .filter_map(|part| Some(format!("part {part} = {}", example.answers.get(part)?)))
+            .collect::<Vec<_>>();
+        if answers.is_empty() {
+            println!("{}: written", path.display());
+        } else {
+            println!("{}: written, {}", path.display(), answers.join(", "));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::read_input;
+
+    use super::*;
+
+    const PAGE: &str = include_str!("../fixtures/puzzle_page.html");
+
+    #[test]
+    fn extracts_examples_and_answers() {
+        let examples = extract(PAGE);
+        let names = examples.iter().map(|e| e.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, vec!["p1.example", "p2.example", "p2.example2"]);
+
+        assert_eq!(
+            examples[0].input,
+            "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n"
+        );
+        assert_eq!(examples[0].answers.get(1), Some("142"));
+        assert_eq!(examples[1].input, "<one> & two\n");
+        assert!(examples[1].answers.is_empty());
+        assert!(examples[2].input.starts_with("two1nine\n"));
+        assert_eq!(examples[2].answers.get(2), Some("281"));
+        assert_eq!(examples[2].answers.get(1), None);
+    }
+
+    #[test]
+    fn part_two_can_reuse_the_part_one_example() {
+        let page = "\
+<article class=\"day-desc\"><pre><code>0 3 6\n</code></pre><p>Total <em><code>18</code></em>.</p></article>
+<article class=\"day-desc\"><p>Now it is <code><em>-3</em></code>.</p></article>";
+        let examples = extract(page);
+        assert_eq!(examples.len(), 1);
+        assert_eq!(
+            examples[0].answers,
+            ExampleAnswers {
+                part1: Some("18".to_string()),
+                part2: Some("-3".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn keeps_existing_examples_unless_forced() {
+        let dir = tempfile::tempdir().expect("temp dir should be created");
+        let mut examples = extract(PAGE);
+        assert!(write_example(dir.path(), &examples[0], false).expect("example should write"));
+
+        examples[0].answers = ExampleAnswers::default();
+        assert!(!write_example(dir.path(), &examples[0], false).expect("example should be kept"));
+        assert!(examples[0].answers_path(dir.path()).exists());
+        assert!(write_example(dir.path(), &examples[0], true).expect("example should write"));
+        assert!(!examples[0].answers_path(dir.path()).exists());
+    }
+
+    // every checked-in example with an answers file still gives those answers
+    #[test]
+    fn examples_match_their_answers() {
+        let mut checked = 0;
+        for solver in registry::SOLVERS {
+            let dir = solver.dir().join("input");
+            let entries = fs::read_dir(&dir).expect("input directory should exist");
+            for entry in entries {
+                let path = entry.expect("input directory should be readable").path();
+                let Some(name) = path
+                    .file_name()
+                    .and_then(|name| name.to_str()?.strip_suffix(".answers.toml"))
+                else {
+                    continue;
+                };
+                let example = dir.join(format!("{name}.txt"));
+                let answers = ExampleAnswers::load(&example).expect("answers should be valid");
+                let input = read_input(&example).expect("example should exist");
+                for part in [1, 2] {
+                    let Some(expected) = answers.get(part) else {
+                        continue;
+                    };
+                    let actual = solver
+                        .solve(&input, &[part])
+                        .expect("example should have an answer");
+                    assert_eq!(actual[0], expected, "{} part {part}", path.display());
+                    checked += 1;
+                }
+            }
+        }
+        assert!(checked > 0);
+    }
+}
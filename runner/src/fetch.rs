@@ -0,0 +1,116 @@
+use std::{fs, path::Path};
+
+use anyhow::{bail, Context, Result};
+use clap::Args;
+
+use crate::{client::Client, config::Config, registry};
+
+#[derive(Args)]
+pub struct FetchArgs {
+    /// Fetch the input of every registered day
+    #[arg(long, conflicts_with_all = ["year", "day"])]
+    all: bool,
+    /// Puzzle year, defaults to the most recent year with solutions
+    #[arg(long)]
+    year: Option<u16>,
+    /// Puzzle day, 1 to 25
+    #[arg(long, required_unless_present = "all")]
+    day: Option<u8>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Cached,
+    Downloaded,
+}
+
+// inputs never change once published, so an existing file is always used as is
+pub fn fetch_input(client: &Client, year: u16, day: u8, path: &Path) -> Result<Fetched> {
+    if path.exists() {
+        return Ok(Fetched::Cached);
+    }
+    let input = client.input(year, day)?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).with_context(|| format!("unable to create {}", dir.display()))?;
+    }
+    fs::write(path, input).with_context(|| format!("unable to write {}", path.display()))?;
+    Ok(Fetched::Downloaded)
+}
+
+pub fn run(args: FetchArgs) -> Result<()> {
+    let solvers = registry::select(args.all, args.year, args.day)?;
+    let client = Client::new(&Config::load()?);
+    let mut failures = 0;
+    for solver in solvers {
+        let path = solver.default_input();
+        match fetch_input(&client, solver.year, solver.day, &path) {
+            Ok(Fetched::Cached) => {
+                println!("{} day {:02}: cached", solver.year, solver.day)
+            }
+            Ok(Fetched::Downloaded) => {
+                println!("{} day {:02}: downloaded", solver.year, solver.day)
+            }
+            Err(err) => {
+                eprintln!("{} day {:02}: {err:#}", solver.year, solver.day);
+                failures += 1;
+            }
+        }
+    }
+    if failures > 0 {
+        bail!("{failures} input(s) could not be fetched");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::stub::StubServer;
+
+    use super::*;
+
+    fn client(server: &StubServer, session: Option<&str>) -> Client {
+        Client::new(&Config {
+            session: session.map(str::to_string),
+            base_url: Some(server.base_url.clone()),
+        })
+    }
+
+    #[test]
+    fn downloads_once_then_uses_cache() {
+        let server = StubServer::start(vec![(200, "1 2 3\n"), (200, "changed\n")]);
+        let dir = tempfile::tempdir().expect("temp dir should be created");
+        let path = dir.path().join("input").join("p1.txt");
+        let client = client(&server, Some("abc"));
+
+        let fetched = fetch_input(&client, 2023, 9, &path).expect("input should download");
+        assert_eq!(fetched, Fetched::Downloaded);
+        let fetched = fetch_input(&client, 2023, 9, &path).expect("input should be cached");
+        assert_eq!(fetched, Fetched::Cached);
+
+        assert_eq!(
+            fs::read_to_string(&path).expect("input should be saved"),
+            "1 2 3\n"
+        );
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].url, "/2023/day/9/input");
+        assert_eq!(requests[0].cookie.as_deref(), Some("session=abc"));
+    }
+
+    #[test]
+    fn failures_are_not_cached() {
+        let server = StubServer::start(vec![(400, "Please log in")]);
+        let dir = tempfile::tempdir().expect("temp dir should be created");
+        let path = dir.path().join("p1.txt");
+
+        let err = fetch_input(&client(&server, None), 2023, 9, &path)
+            .expect_err("no session should be an error");
+        assert!(err.to_string().contains("session"));
+        assert!(server.requests().is_empty());
+
+        fetch_input(&client(&server, Some("expired")), 2023, 9, &path)
+            .expect_err("a bad status should be an error");
+        assert!(!path.exists());
+    }
+}
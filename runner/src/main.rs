@@ -0,0 +1,173 @@
+#[cfg(feature = "tui")]
+mod animate;
+mod answers;
+mod bench;
+mod client;
+mod config;
+mod examples;
+mod fetch;
+mod plugin;
+mod registry;
+mod render;
+mod repl;
+mod scaffold;
+mod serve;
+#[cfg(test)]
+mod stub;
+mod submit;
+mod verify;
+
+use std::{path::PathBuf, time::Instant};
+
+use anyhow::{bail, Result};
+use aoc_common::{read_input, Format, Report};
+use clap::{Args, Parser, Subcommand};
+
+#[cfg(feature = "tui")]
+use animate::AnimateArgs;
+use bench::BenchArgs;
+use examples::ExamplesArgs;
+use fetch::FetchArgs;
+use plugin::PluginArgs;
+use render::RenderArgs;
+use repl::ReplArgs;
+use scaffold::NewArgs;
+use serve::ServeArgs;
+use submit::SubmitArgs;
+use verify::VerifyArgs;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Create a new day's crate from the template and register it with the runner
+    New(NewArgs),
+    /// Run a single day/part, a whole day, or every registered solution
+    Run(RunArgs),
+    /// Time the parse step and each part over repeated runs
+    Bench(BenchArgs),
+    /// Download puzzle inputs into each day's `input/` directory, skipping cached ones
+    Fetch(FetchArgs),
+    /// Extract the examples and their answers from a saved puzzle page into a day's `input/`
+    Examples(ExamplesArgs),
+    /// Submit an answer, refusing ones earlier submissions show to be wrong
+    Submit(SubmitArgs),
+    /// Check every solution against the accepted answers in `answers.toml`
+    Verify(VerifyArgs),
+    /// Draw a grid puzzle's input and solution as a PNG or SVG image
+    Render(RenderArgs),
+    /// Run days built as plugins from a directory, without rebuilding the runner
+    Plugin(PluginArgs),
+    /// Answer solve requests over HTTP
+    Serve(ServeArgs),
+    /// Explore a day's parsed input interactively, one command at a time
+    Repl(ReplArgs),
+    /// Play a solution step by step in the terminal
+    #[cfg(feature = "tui")]
+    Animate(AnimateArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Run every registered day and part against its default input
+    #[arg(long, conflicts_with_all = ["year", "day", "part", "input"])]
+    all: bool,
+    /// Puzzle year, defaults to the most recent year with solutions
+    #[arg(long)]
+    year: Option<u16>,
+    /// Puzzle day, 1 to 25
+    #[arg(long, required_unless_present = "all")]
+    day: Option<u8>,
+    /// Only run this part, otherwise both parts are run
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Puzzle input to use instead of the day's `input/p1.txt`, or `-` for stdin
+    #[arg(long)]
+    input: Option<PathBuf>,
+    /// How to print answers: plain, human (with timings) or json
+    #[arg(long, default_value_t = Format::Human)]
+    format: Format,
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::New(args) => scaffold::run(args),
+        Command::Run(args) => run(args),
+        Command::Bench(args) => bench::run(args),
+        Command::Fetch(args) => fetch::run(args),
+        Command::Examples(args) => examples::run(args),
+        Command::Submit(args) => submit::run(args),
+        Command::Verify(args) => verify::run(args),
+        Command::Render(args) => render::run(args),
+        Command::Plugin(args) => plugin::run(args),
+        Command::Serve(args) => serve::run(args),
+        Command::Repl(args) => repl::run(args),
+        #[cfg(feature = "tui")]
+        Command::Animate(args) => animate::run(args),
+    }
+}
+
+fn run(args: RunArgs) -> Result<()> {
+    let solvers = registry::select(args.all, args.year, args.day)?;
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let mut failures = 0;
+    for solver in solvers {
+        let label = format!("{} day {:02}", solver.year, solver.day);
+        let path = args.input.clone().unwrap_or_else(|| solver.default_input());
+        let input = match read_input(&path) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("{label}: {err}");
+                failures += 1;
+                continue;
+            }
+        };
+        let start = Instant::now();
+        let parsed = match (solver.parse)(&input) {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                eprintln!("{label}: {}", err.in_file(&path));
+                failures += 1;
+                continue;
+            }
+        };
+        let parse = start.elapsed();
+        let mut failed = false;
+        for &part in &parts {
+            let start = Instant::now();
+            match solver.part(part)(parsed.as_ref()) {
+                Ok(answer) => {
+                    let report = Report {
+                        year: solver.year,
+                        day: solver.day,
+                        part,
+                        answer,
+                        parse: Some(parse),
+                        solve: start.elapsed(),
+                    };
+                    println!("{}", report.render(args.format));
+                }
+                Err(err) => {
+                    eprintln!("{label} part {part}: {err}");
+                    failed = true;
+                }
+            }
+        }
+        if failed {
+            failures += 1;
+        }
+    }
+    if failures > 0 {
+        bail!("{failures} day(s) could not be run");
+    }
+    Ok(())
+}
@@ -0,0 +1,353 @@
+// runs days built as `cdylib` plugins, loaded from a directory at runtime through the C ABI
+// in `aoc_common::plugin`, so a day can be rebuilt and re-run without rebuilding the runner
+
+use std::{
+    env::consts::DLL_EXTENSION,
+    ffi::CStr,
+    fs,
+    path::{Path, PathBuf},
+    time::Instant,
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+use aoc_common::{
+    plugin::{FreeFn, InfoFn, SolveFn, ABI_VERSION, FREE_SYMBOL, INFO_SYMBOL, SOLVE_SYMBOL},
+    read_input, Format, Report,
+};
+use clap::Args;
+use libloading::Library;
+
+use crate::registry;
+
+#[derive(Args)]
+pub struct PluginArgs {
+    /// Directory to load plugins from, e.g. `target/release`
+    dir: PathBuf,
+    /// Puzzle year, defaults to the most recent year among the plugins
+    #[arg(long)]
+    year: Option<u16>,
+    /// Puzzle day, otherwise every plugin found is run
+    #[arg(long)]
+    day: Option<u8>,
+    /// Only run this part, otherwise both parts are run
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Puzzle input to use instead of the day's `input/p1.txt`, or `-` for stdin
+    #[arg(long, requires = "day")]
+    input: Option<PathBuf>,
+    /// How to print answers: plain, human (with timings) or json
+    #[arg(long, default_value_t = Format::Human)]
+    format: Format,
+}
+
+pub struct Plugin {
+    pub path: PathBuf,
+    pub year: u16,
+    pub day: u8,
+    solve: SolveFn,
+    free: FreeFn,
+    // the functions above point into the library, so it has to stay loaded while they're used
+    _library: Library,
+}
+
+impl Plugin {
+    pub fn load(path: &Path) -> Result<Plugin> {
+        // SAFETY: loading a library runs its initialisers, which is as safe as the plugins
+        // we're asked to load; for days built from this workspace there aren't any
+        let library = unsafe { Library::new(path) }
+            .with_context(|| format!("unable to load {}", path.display()))?;
+        // SAFETY: each symbol is declared with the type `aoc_common::export_plugin!` gives it,
+        // and the version check below catches plugins built against another ABI
+        let (info, solve, free) = unsafe {
+            let info = *library
+                .get::<InfoFn>(INFO_SYMBOL)
+                .with_context(|| format!("{} isn't a plugin", path.display()))?;
+            let info = info();
+            if info.abi_version != ABI_VERSION {
+                bail!(
+                    "{} was built for plugin ABI version {}, not {ABI_VERSION}",
+                    path.display(),
+                    info.abi_version
+                );
+            }
+            let solve = *library
+                .get::<SolveFn>(SOLVE_SYMBOL)
+                .with_context(|| format!("{} doesn't export aoc_solve", path.display()))?;
+            let free = *library
+                .get::<FreeFn>(FREE_SYMBOL)
+                .with_context(|| format!("{} doesn't export aoc_free_answer", path.display()))?;
+            (info, solve, free)
+        };
+        Ok(Plugin {
+            path: path.to_path_buf(),
+            year: info.year,
+            day: info.day,
+            solve,
+            free,
+            _library: library,
+        })
+    }
+
+    pub fn solve(&self, part: u8, input: &str) -> Result<String> {
+        // SAFETY: the pointer and length come from the same `&str`, and the answer's text is
+        // copied out before being handed back to the plugin to free
+        let (ok, text) = unsafe {
+            let answer = (self.solve)(self.day, part, input.as_ptr(), input.len());
+            if answer.text.is_null() {
+                bail!("{} returned no answer", self.path.display());
+            }
+            let text = CStr::from_ptr(answer.text).to_string_lossy().into_owned();
+            (self.free)(answer.text);
+            (answer.ok, text)
+        };
+        if ok {
+            Ok(text)
+        } else {
+            Err(anyhow!(text))
+        }
+    }
+}
+
+// loads every library in `dir`, in name order, skipping ones that aren't plugins and days
+// that an earlier library already provides
+pub fn discover(dir: &Path) -> Result<Vec<Plugin>> {
+    let mut paths = fs::read_dir(dir)
+        .with_context(|| format!("unable to read {}", dir.display()))?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<std::io::Result<Vec<_>>>()
+        .with_context(|| format!("unable to read {}", dir.display()))?;
+    paths.retain(|path| path.is_file() && path.extension() == Some(DLL_EXTENSION.as_ref()));
+    paths.sort();
+
+    let mut plugins = Vec::<Plugin>::new();
+    for path in paths {
+        let plugin = match Plugin::load(&path) {
+            Ok(plugin) => plugin,
+            Err(err) => {
+                eprintln!("skipping {}: {err:#}", path.display());
+                continue;
+            }
+        };
+        if let Some(earlier) = plugins
+            .iter()
+            .find(|earlier| (earlier.year, earlier.day) == (plugin.year, plugin.day))
+        {
+            eprintln!(
+                "skipping {}: {} day {:02} is already loaded from {}",
+                path.display(),
+                plugin.year,
+                plugin.day,
+                earlier.path.display()
+            );
+            continue;
+        }
+        plugins.push(plugin);
+    }
+    Ok(plugins)
+}
+
+pub fn run(args: PluginArgs) -> Result<()> {
+    let mut plugins = discover(&args.dir)?;
+    if plugins.is_empty() {
+        bail!("no plugins found in {}", args.dir.display());
+    }
+    plugins.sort_by_key(|plugin| (plugin.year, plugin.day));
+    if let Some(day) = args.day {
+        let year = args.year.unwrap_or_else(|| {
+            plugins
+                .iter()
+                .map(|plugin| plugin.year)
+                .max()
+                .expect("there should be a plugin")
+        });
+        plugins.retain(|plugin| (plugin.year, plugin.day) == (year, day));
+        if plugins.is_empty() {
+            bail!("no plugin for {year} day {day} in {}", args.dir.display());
+        }
+    } else if let Some(year) = args.year {
+        plugins.retain(|plugin| plugin.year == year);
+        if plugins.is_empty() {
+            bail!("no plugins for {year} in {}", args.dir.display());
+        }
+    }
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let mut failures = 0;
+    for plugin in &plugins {
+        let label = format!("{} day {:02}", plugin.year, plugin.day);
+        let path = args
+            .input
+            .clone()
+            .unwrap_or_else(|| registry::default_input(plugin.year, plugin.day));
+        let input = match read_input(&path) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("{label}: {err}");
+                failures += 1;
+                continue;
+            }
+        };
+        let mut failed = false;
+        for &part in &parts {
+            // the plugin parses the input for every part, so the solve time includes it
+            let start = Instant::now();
+            match plugin.solve(part, &input) {
+                Ok(answer) => {
+                    let report = Report {
+                        year: plugin.year,
+                        day: plugin.day,
+                        part,
+                        answer,
+                        parse: None,
+                        solve: start.elapsed(),
+                    };
+                    println!("{}", report.render(args.format));
+                }
+                Err(err) => {
+                    eprintln!("{label} part {part}: {err}");
+                    failed = true;
+                }
+            }
+        }
+        if failed {
+            failures += 1;
+        }
+    }
+    if failures > 0 {
+        bail!("{failures} day(s) could not be run");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env::consts::DLL_PREFIX, process::Command};
+
+    use super::*;
+
+    #[test]
+    fn skips_libraries_that_arent_plugins() {
+        let dir = tempfile::tempdir().expect("temp dir should be created");
+        fs::write(
+            dir.path().join(format!("libjunk.{DLL_EXTENSION}")),
+            "not a library",
+        )
+        .expect("junk library should be written");
+        fs::write(dir.path().join("notes.txt"), "not a library either")
+            .expect("notes should be written");
+
+        let err = Plugin::load(&dir.path().join(format!("libjunk.{DLL_EXTENSION}")))
+            .err()
+            .expect("junk shouldn't load");
+        assert!(err.to_string().starts_with("unable to load"), "{err}");
+        let plugins = discover(dir.path()).expect("dir should be readable");
+        assert!(plugins.is_empty());
+
+        let args = PluginArgs {
+            dir: dir.path().to_path_buf(),
+            year: None,
+            day: Some(5),
+            part: None,
+            input: None,
+            format: Format::Plain,
+        };
+        let err = run(args).expect_err("there are no plugins to run");
+        assert!(err.to_string().starts_with("no plugins found in"), "{err}");
+    }
+
+    // builds day 5 the way the README says to, into its own target dir so it doesn't wait on
+    // the build lock `cargo test` is holding
+    fn build_day_05() -> PathBuf {
+        let target = registry::workspace_root()
+            .join("target")
+            .join("plugin-test");
+        let output = Command::new(env!("CARGO"))
+            .args(["rustc", "-p", "day-05", "--lib", "--features", "plugin"])
+            .args(["--crate-type", "cdylib", "--target-dir"])
+            .arg(&target)
+            .current_dir(registry::workspace_root())
+            .output()
+            .expect("cargo should run");
+        assert!(
+            output.status.success(),
+            "day 5 should build as a plugin: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        target
+            .join("debug")
+            .join(format!("{DLL_PREFIX}day_05.{DLL_EXTENSION}"))
+    }
+
+    // a plugin from before the current ABI, which only has to get as far as `aoc_plugin_info`
+    fn build_old_plugin(dir: &Path) -> PathBuf {
+        let source = dir.join("old.rs");
+        fs::write(
+            &source,
+            r#"
+            #[repr(C)]
+            pub struct PluginInfo { abi_version: u32, year: u16, day: u8 }
+
+            #[no_mangle]
+            pub extern "C" fn aoc_plugin_info() -> PluginInfo {
+                PluginInfo { abi_version: 0, year: 2023, day: 5 }
+            }
+            "#,
+        )
+        .expect("old plugin source should be written");
+        let library = dir.join(format!("{DLL_PREFIX}old.{DLL_EXTENSION}"));
+        let output = Command::new(option_env!("RUSTC").unwrap_or("rustc"))
+            .args(["--crate-type", "cdylib", "--edition", "2021", "-o"])
+            .args([&library, &source])
+            .output()
+            .expect("rustc should run");
+        assert!(
+            output.status.success(),
+            "old plugin should build: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        library
+    }
+
+    #[test]
+    fn loads_a_day_built_as_a_plugin() {
+        let built = build_day_05();
+        let dir = tempfile::tempdir().expect("temp dir should be created");
+        // `discover` goes in name order, so the day's own plugin comes before the old one
+        let library = dir
+            .path()
+            .join(format!("{DLL_PREFIX}day_05.{DLL_EXTENSION}"));
+        fs::copy(&built, &library).expect("plugin should be copied");
+
+        let plugin = Plugin::load(&library).expect("day 5 should load");
+        assert_eq!((plugin.year, plugin.day), (2023, 5));
+        let example = read_input(
+            registry::day_dir(2023, 5)
+                .join("input")
+                .join("p1.example.txt"),
+        )
+        .expect("example input should exist");
+        // every answer's text goes back to the plugin to free, so a double free would crash here
+        for _ in 0..100 {
+            assert_eq!(plugin.solve(1, &example).expect("part 1 solves"), "35");
+        }
+        assert_eq!(plugin.solve(2, &example).expect("part 2 solves"), "46");
+        let err = plugin.solve(1, "junk").expect_err("junk doesn't parse");
+        assert!(err.to_string().starts_with("<input>:1:1:"), "{err}");
+
+        let old = build_old_plugin(dir.path());
+        let err = Plugin::load(&old).err().expect("old ABI shouldn't load");
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "{} was built for plugin ABI version 0, not {ABI_VERSION}",
+                old.display()
+            )
+        );
+        let plugins = discover(dir.path()).expect("dir should be readable");
+        assert_eq!(plugins.len(), 1);
+        assert_eq!(plugins[0].path, library);
+    }
+}
@@ -0,0 +1,240 @@
+use std::{
+    any::Any,
+    fmt::Debug,
+    path::{Path, PathBuf},
+};
+
+use anyhow::anyhow;
+use aoc_common::{Animate, Canvas, Explore, Player, Render, Result, Solution};
+
+// a day's `Solution` with its parsed input type erased, so every day fits in one table
+// and the parse step can still be run on its own
+pub type ParseFn = fn(&str) -> Result<Box<dyn Any>>;
+pub type PartFn = fn(&dyn Any) -> Result<String>;
+pub type ShowFn = fn(&dyn Any) -> String;
+pub type RenderFn = fn(&dyn Any) -> Result<Canvas>;
+pub type AnimateFn = fn(&dyn Any, Player<'_>) -> Result<()>;
+pub type ExploreFn = fn(&dyn Any, &str, &str) -> Option<Result<String>>;
+
+/// A day's own REPL commands, from its `Explore` implementation.
+pub struct Explorer {
+    pub commands: &'static [(&'static str, &'static str)],
+    pub run: ExploreFn,
+}
+
+pub struct Solver {
+    pub year: u16,
+    pub day: u8,
+    pub parse: ParseFn,
+    pub part1: PartFn,
+    pub part2: PartFn,
+    pub show: ShowFn,
+    // only for days that implement `Render`, `Animate` and `Explore`
+    pub render: Option<RenderFn>,
+    // only played by `aoc animate`, which needs the `tui` feature
+    #[cfg_attr(not(feature = "tui"), allow(dead_code))]
+    pub animate: Option<AnimateFn>,
+    pub explore: Option<fn() -> Explorer>,
+}
+
+fn parse<S: Solution>(input: &str) -> Result<Box<dyn Any>>
+where
+    S::Parsed: 'static,
+{
+    Ok(Box::new(S::parse(input)?))
+}
+
+fn parsed<S: Solution>(parsed: &dyn Any) -> &S::Parsed
+where
+    S::Parsed: 'static,
+{
+    parsed
+        .downcast_ref()
+        .expect("parsed input should come from the same day's parser")
+}
+
+fn part1<S: Solution>(input: &dyn Any) -> Result<String>
+where
+    S::Parsed: 'static,
+{
+    S::part1(parsed::<S>(input)).map(|answer| answer.to_string())
+}
+
+fn part2<S: Solution>(input: &dyn Any) -> Result<String>
+where
+    S::Parsed: 'static,
+{
+    S::part2(parsed::<S>(input)).map(|answer| answer.to_string())
+}
+
+fn show<S: Solution>(input: &dyn Any) -> String
+where
+    S::Parsed: Debug + 'static,
+{
+    format!("{:#?}", parsed::<S>(input))
+}
+
+fn render<S: Render>(input: &dyn Any) -> Result<Canvas>
+where
+    S::Parsed: 'static,
+{
+    S::render(parsed::<S>(input))
+}
+
+fn animate<S: Animate>(input: &dyn Any, player: Player<'_>) -> Result<()>
+where
+    S::Parsed: 'static,
+{
+    S::animate(parsed::<S>(input), player)
+}
+
+fn explore<S: Explore>() -> Explorer
+where
+    S::Parsed: 'static,
+{
+    Explorer {
+        commands: S::COMMANDS,
+        run: |input, command, arg| S::explore(parsed::<S>(input), command, arg),
+    }
+}
+
+impl Solver {
+    pub fn part(&self, part: u8) -> PartFn {
+        match part {
+            1 => self.part1,
+            2 => self.part2,
+            _ => panic!("puzzles only have two parts, got part {part}"),
+        }
+    }
+
+    // parses the input once and returns the answer for each requested part, in order
+    pub fn solve(&self, input: &str, parts: &[u8]) -> Result<Vec<String>> {
+        let parsed = (self.parse)(input)?;
+        parts
+            .iter()
+            .map(|part| self.part(*part)(parsed.as_ref()))
+            .collect()
+    }
+
+    pub fn dir(&self) -> PathBuf {
+        day_dir(self.year, self.day)
+    }
+
+    pub fn default_input(&self) -> PathBuf {
+        default_input(self.year, self.day)
+    }
+
+    pub fn example_input(&self) -> PathBuf {
+        self.dir().join("input").join("p1.example.txt")
+    }
+}
+
+// any extras after the solution, like `render`, name the optional traits the day implements
+macro_rules! solver {
+    ($year:literal, $day:literal, $solution:path $(, $extra:ident)*) => {
+        Solver {
+            $($extra: Some($extra::<$solution>),)*
+            ..Solver {
+                year: $year,
+                day: $day,
+                parse: parse::<$solution>,
+                part1: part1::<$solution>,
+                part2: part2::<$solution>,
+                show: show::<$solution>,
+                render: None,
+                animate: None,
+                explore: None,
+            }
+        }
+    };
+}
+
+pub const SOLVERS: &[Solver] = &[
+    solver!(2023, 1, day_01::Day01),
+    solver!(2023, 2, day_02::Day02),
+    solver!(2023, 3, day_03::Day03),
+    solver!(2023, 4, day_04::Day04),
+    solver!(2023, 5, day_05::Day05, explore),
+    solver!(2023, 6, day_06::Day06),
+    solver!(2023, 7, day_07::Day07, explore),
+    solver!(2023, 8, day_08::Day08, explore),
+    solver!(2023, 9, day_09::Day09),
+    solver!(2023, 10, day_10::Day10, render, animate),
+    solver!(2023, 11, day_11::Day11, render),
+    solver!(2023, 12, day_12::Day12),
+];
+
+pub fn find(year: u16, day: u8) -> Option<&'static Solver> {
+    SOLVERS.iter().find(|s| s.year == year && s.day == day)
+}
+
+// the solvers picked out by the `--all`/`--year`/`--day` flags shared by the subcommands
+pub fn select(
+    all: bool,
+    year: Option<u16>,
+    day: Option<u8>,
+) -> anyhow::Result<Vec<&'static Solver>> {
+    if all {
+        return Ok(SOLVERS.iter().collect());
+    }
+    let year = year.unwrap_or_else(latest_year);
+    let day = day.expect("clap should require a day");
+    find(year, day)
+        .map(|solver| vec![solver])
+        .ok_or_else(|| anyhow!("no solution registered for {year} day {day}"))
+}
+
+pub fn latest_year() -> u16 {
+    SOLVERS
+        .iter()
+        .map(|s| s.year)
+        .max()
+        .expect("registry should not be empty")
+}
+
+// where a day's crate lives, whether or not it has been registered yet
+pub fn day_dir(year: u16, day: u8) -> PathBuf {
+    workspace_root()
+        .join(year.to_string())
+        .join(format!("day-{day:02}"))
+}
+
+// where a day's real input goes, also for days loaded as plugins that aren't registered here
+pub fn default_input(year: u16, day: u8) -> PathBuf {
+    day_dir(year, day).join("input").join("p1.txt")
+}
+
+pub fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("runner should live inside the workspace")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use aoc_common::read_input;
+
+    use super::*;
+
+    #[test]
+    fn days_are_unique() {
+        let days = SOLVERS
+            .iter()
+            .map(|s| (s.year, s.day))
+            .collect::<HashSet<_>>();
+        assert_eq!(days.len(), SOLVERS.len());
+    }
+
+    #[test]
+    fn dispatches_to_day() {
+        let solver = find(2023, 5).expect("day 5 should be registered");
+        let input = read_input(solver.dir().join("input/p1.example.txt"))
+            .expect("example input should exist");
+        let answers = solver
+            .solve(&input, &[1, 2])
+            .expect("example should have answers");
+        assert_eq!(answers, vec!["35", "46"]);
+    }
+}
@@ -0,0 +1,133 @@
+use std::{fs, path::PathBuf};
+
+use anyhow::{anyhow, bail, Context, Result};
+use aoc_common::read_input;
+use clap::Args;
+
+use crate::registry;
+
+#[derive(Args)]
+pub struct RenderArgs {
+    /// Puzzle year, defaults to the most recent year with solutions
+    #[arg(long)]
+    year: Option<u16>,
+    /// Puzzle day, 1 to 25
+    #[arg(long)]
+    day: u8,
+    /// Puzzle input to use instead of the day's `input/p1.txt`, or `-` for stdin
+    #[arg(long)]
+    input: Option<PathBuf>,
+    /// Width of each grid cell in pixels, at most 32 so a big grid stays a sensible size
+    #[arg(long, default_value_t = 8, value_parser = clap::value_parser!(u16).range(1..=32))]
+    cell_size: u16,
+    /// Image to write, as PNG or SVG going by its extension
+    output: PathBuf,
+}
+
+pub fn run(args: RenderArgs) -> Result<()> {
+    let solver = registry::select(false, args.year, Some(args.day))?[0];
+    let label = format!("{} day {:02}", solver.year, solver.day);
+    let Some(render) = solver.render else {
+        bail!("{label} can't be rendered");
+    };
+    let extension = args
+        .output
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(str::to_ascii_lowercase);
+    if !matches!(extension.as_deref(), Some("png" | "svg")) {
+        bail!(
+            "unable to tell the image format of {}, it should end in .png or .svg",
+            args.output.display()
+        );
+    }
+
+    let path = args.input.unwrap_or_else(|| solver.default_input());
+    let input = read_input(&path).map_err(|err| anyhow!("{label}: {err}"))?;
+    let parsed =
+        (solver.parse)(&input).map_err(|err| anyhow!("{label}: {}", err.in_file(&path)))?;
+    let canvas = render(parsed.as_ref())
+        .map_err(|err| anyhow!("{label}: {err}"))?
+        .with_cell_size(args.cell_size.into());
+    let image = match extension.as_deref() {
+        Some("png") => canvas.to_png().map_err(|err| anyhow!("{label}: {err}"))?,
+        _ => canvas.to_svg().into_bytes(),
+    };
+    fs::write(&args.output, image)
+        .with_context(|| format!("unable to write {}", args.output.display()))?;
+    let (width, height) = canvas.size();
+    println!("{label}: wrote {width}x{height} {}", args.output.display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(day: u8, output: PathBuf) -> RenderArgs {
+        let solver = registry::find(2023, day).expect("day should be registered");
+        RenderArgs {
+            year: Some(2023),
+            day,
+            input: Some(solver.example_input()),
+            cell_size: 4,
+            output,
+        }
+    }
+
+    #[test]
+    fn writes_images_by_extension() {
+        let dir = tempfile::tempdir().expect("temp dir should be created");
+        let png = dir.path().join("loop.png");
+        run(args(10, png.clone())).expect("day 10 should render");
+        let image = fs::read(&png).expect("png should be written");
+        assert!(image.starts_with(b"\x89PNG"));
+
+        let svg = dir.path().join("galaxies.SVG");
+        run(args(11, svg.clone())).expect("day 11 should render");
+        let image = fs::read_to_string(&svg).expect("svg should be written");
+        assert!(image.starts_with("<svg "), "{image}");
+
+        let err = run(args(10, dir.path().join("loop.gif"))).expect_err("gif isn't supported");
+        assert!(
+            err.to_string().contains("should end in .png or .svg"),
+            "{err}"
+        );
+        let err = run(args(5, dir.path().join("seeds.png"))).expect_err("day 5 has no grid");
+        assert_eq!(err.to_string(), "2023 day 05 can't be rendered");
+    }
+
+    #[test]
+    fn refuses_empty_and_oversized_images() {
+        let dir = tempfile::tempdir().expect("temp dir should be created");
+        let empty = dir.path().join("empty.txt");
+        fs::write(&empty, "").expect("empty input should be written");
+        let err = run(RenderArgs {
+            input: Some(empty),
+            ..args(11, dir.path().join("empty.png"))
+        })
+        .expect_err("an empty image has no pixels");
+        assert_eq!(
+            err.to_string(),
+            "2023 day 11: a 0x0 image can't be written as a PNG"
+        );
+
+        #[derive(clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            render: RenderArgs,
+        }
+        let parse = |size: &str| {
+            <Cli as clap::Parser>::try_parse_from([
+                "render",
+                "--day",
+                "10",
+                "--cell-size",
+                size,
+                "a.png",
+            ])
+        };
+        assert!(parse("32").is_ok());
+        assert!(parse("65535").is_err());
+    }
+}
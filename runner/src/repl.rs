@@ -0,0 +1,270 @@
+// an interactive session with one day: the input stays parsed between commands, so the model
+// can be looked at from several angles without recompiling a test to print it
+
+use std::{
+    any::Any,
+    io::{self, BufRead, IsTerminal, Write},
+    ops::ControlFlow,
+    path::{Path, PathBuf},
+    time::Instant,
+};
+
+use anyhow::{anyhow, bail, Result};
+use aoc_common::read_input;
+use clap::Args;
+
+use crate::registry::{self, Solver};
+
+#[derive(Args)]
+pub struct ReplArgs {
+    /// Puzzle year, defaults to the most recent year with solutions
+    #[arg(long)]
+    year: Option<u16>,
+    /// Puzzle day, 1 to 25
+    #[arg(long)]
+    day: u8,
+    /// Puzzle input to use instead of the day's `input/p1.txt`
+    #[arg(long)]
+    input: Option<PathBuf>,
+}
+
+const COMMANDS: &[(&str, &str)] = &[
+    (
+        "parse [path]",
+        "parse the input again, or another input from now on",
+    ),
+    ("show", "print the parsed input"),
+    ("part1, part2", "solve a part"),
+    (
+        "time [command]",
+        "run a command and say how long it took, by default parse and both parts",
+    ),
+    ("help", "list the commands"),
+    ("quit", "leave, as does the end of the input"),
+];
+
+struct Session {
+    solver: &'static Solver,
+    path: PathBuf,
+    parsed: Option<Box<dyn Any>>,
+}
+
+impl Session {
+    fn new(solver: &'static Solver, path: PathBuf) -> Self {
+        Session {
+            solver,
+            path,
+            parsed: None,
+        }
+    }
+
+    fn parsed(&self) -> Result<&dyn Any> {
+        self.parsed
+            .as_deref()
+            .ok_or_else(|| anyhow!("nothing is parsed, try parse"))
+    }
+
+    fn parse(&mut self, path: Option<&Path>) -> Result<String> {
+        if let Some(path) = path {
+            self.path = path.to_path_buf();
+        }
+        // a failed parse shouldn't leave the last input looking like the current one
+        self.parsed = None;
+        let input = read_input(&self.path)?;
+        let parsed = (self.solver.parse)(&input).map_err(|err| err.in_file(&self.path))?;
+        self.parsed = Some(parsed);
+        Ok(format!("parsed {}", self.path.display()))
+    }
+
+    fn help(&self) -> String {
+        let own = self.solver.explore.map(|explore| explore().commands);
+        let commands = COMMANDS.iter().chain(own.into_iter().flatten());
+        let width = commands
+            .clone()
+            .map(|(usage, _)| usage.len())
+            .max()
+            .unwrap_or_default();
+        commands
+            .map(|(usage, about)| format!("{usage:width$}  {about}"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    // one of the day's own commands, if it has one by that name
+    fn explore(&self, command: &str, arg: &str) -> Option<Result<String>> {
+        let explorer = self.solver.explore?();
+        explorer
+            .commands
+            .iter()
+            .any(|(usage, _)| usage.split_whitespace().next() == Some(command))
+            .then(|| {
+                let parsed = self.parsed()?;
+                match (explorer.run)(parsed, command, arg) {
+                    Some(output) => Ok(output?),
+                    None => bail!("{command} is listed but the day doesn't run it"),
+                }
+            })
+    }
+
+    fn time(&mut self, command: &str, arg: &str) -> Result<String> {
+        if command.is_empty() {
+            let mut lines = Vec::new();
+            for command in ["parse", "part1", "part2"] {
+                let start = Instant::now();
+                let output = self.run(command, "")?;
+                lines.push(format!("{command}: {output} in {:.2?}", start.elapsed()));
+            }
+            return Ok(lines.join("\n"));
+        }
+        let start = Instant::now();
+        let output = self.run(command, arg)?;
+        Ok(format!("{output}\n{command} took {:.2?}", start.elapsed()))
+    }
+
+    fn run(&mut self, command: &str, arg: &str) -> Result<String> {
+        match command {
+            "" => Ok(String::new()),
+            "help" => Ok(self.help()),
+            "parse" => self.parse((!arg.is_empty()).then_some(Path::new(arg))),
+            "show" => Ok((self.solver.show)(self.parsed()?)),
+            "part1" => Ok((self.solver.part1)(self.parsed()?)?),
+            "part2" => Ok((self.solver.part2)(self.parsed()?)?),
+            "time" => {
+                let (command, arg) = split(arg);
+                self.time(command, arg)
+            }
+            _ => self
+                .explore(command, arg)
+                .unwrap_or_else(|| Err(anyhow!("no command {command:?}, try help"))),
+        }
+    }
+
+    // runs one line, breaking when it's time to leave
+    fn execute(&mut self, line: &str) -> ControlFlow<(), Result<String>> {
+        match split(line) {
+            ("quit" | "exit", _) => ControlFlow::Break(()),
+            (command, arg) => ControlFlow::Continue(self.run(command, arg)),
+        }
+    }
+}
+
+// a command and the rest of the line
+fn split(line: &str) -> (&str, &str) {
+    let line = line.trim();
+    match line.split_once(char::is_whitespace) {
+        Some((command, arg)) => (command, arg.trim()),
+        None => (line, ""),
+    }
+}
+
+fn print(output: Result<String>) {
+    match output {
+        Ok(output) if output.is_empty() => {}
+        Ok(output) => println!("{output}"),
+        Err(err) => eprintln!("error: {err}"),
+    }
+}
+
+pub fn run(args: ReplArgs) -> Result<()> {
+    let solver = registry::select(false, args.year, Some(args.day))?[0];
+    let label = format!("{} day {:02}", solver.year, solver.day);
+    let path = args.input.unwrap_or_else(|| solver.default_input());
+    if path == Path::new("-") {
+        bail!("{label}: the REPL reads its commands from stdin, so the input has to be a file");
+    }
+
+    // prompts only make sense to a person, not to commands piped in from a file
+    let interactive = io::stdin().is_terminal();
+    if interactive {
+        println!("{label}, type help for the commands");
+    }
+    let mut session = Session::new(solver, path);
+    print(session.parse(None));
+    let mut lines = io::stdin().lock().lines();
+    loop {
+        if interactive {
+            print!("{label}> ");
+            io::stdout().flush()?;
+        }
+        let Some(line) = lines.next() else {
+            break;
+        };
+        match session.execute(&line?) {
+            ControlFlow::Break(()) => break,
+            ControlFlow::Continue(output) => print(output),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(day: u8) -> Session {
+        let solver = registry::find(2023, day).expect("day should be registered");
+        Session::new(solver, solver.example_input())
+    }
+
+    fn output(session: &mut Session, line: &str) -> Result<String> {
+        match session.execute(line) {
+            ControlFlow::Continue(output) => output,
+            ControlFlow::Break(()) => panic!("{line:?} shouldn't quit"),
+        }
+    }
+
+    #[test]
+    fn keeps_the_parsed_input_between_commands() {
+        let mut session = session(5);
+        let err = output(&mut session, "part1").expect_err("nothing is parsed yet");
+        assert_eq!(err.to_string(), "nothing is parsed, try parse");
+
+        let parsed = output(&mut session, "parse").expect("example should parse");
+        assert!(parsed.ends_with("p1.example.txt"), "{parsed}");
+        assert_eq!(output(&mut session, "part1").expect("part 1 solves"), "35");
+        assert_eq!(
+            output(&mut session, " part2 ").expect("part 2 solves"),
+            "46"
+        );
+        let shown = output(&mut session, "show").expect("show should print");
+        assert!(shown.starts_with("Almanac {\n    seeds: ["), "{shown}");
+        let trace = output(&mut session, "map 13").expect("day 5 has map");
+        assert_eq!(trace, "13 -> 13 -> 52 -> 41 -> 34 -> 34 -> 35 -> 35");
+
+        let timed = output(&mut session, "time part1").expect("part 1 solves");
+        assert!(timed.starts_with("35\npart1 took "), "{timed}");
+        let timed = output(&mut session, "time").expect("every step runs");
+        assert_eq!(timed.lines().count(), 3, "{timed}");
+        assert!(timed
+            .lines()
+            .nth(2)
+            .is_some_and(|line| line.starts_with("part2: 46 in ")));
+
+        let help = output(&mut session, "help").expect("help should list commands");
+        assert!(help.contains("\nmap <seed>  "), "{help}");
+        let err = output(&mut session, "hand 32T3K").expect_err("hand is day 7's");
+        assert_eq!(err.to_string(), "no command \"hand\", try help");
+
+        let err = output(&mut session, "parse missing.txt").expect_err("file doesn't exist");
+        assert!(
+            err.to_string().starts_with("unable to read missing.txt"),
+            "{err}"
+        );
+        output(&mut session, "part1").expect_err("a failed parse forgets the last input");
+        assert!(session.execute("quit").is_break());
+    }
+
+    #[test]
+    fn days_without_commands_still_have_the_basics() {
+        let mut session = session(6);
+        output(&mut session, "parse").expect("example should parse");
+        assert_eq!(output(&mut session, "part1").expect("part 1 solves"), "288");
+        let help = output(&mut session, "help").expect("help should list commands");
+        assert_eq!(help.lines().count(), COMMANDS.len());
+        assert!(output(&mut session, "map 79").is_err());
+        assert_eq!(
+            output(&mut session, "").expect("blank lines do nothing"),
+            ""
+        );
+    }
+}
@@ -0,0 +1,292 @@
+use std::{fs, path::Path};
+
+use anyhow::{bail, Context, Result};
+use clap::Args;
+
+use crate::{
+    client::Client,
+    config::Config,
+    examples::{self, Example},
+    fetch::fetch_input,
+    registry::{self, workspace_root},
+};
+
+const CARGO_TOML: &str = include_str!("../templates/day/Cargo.toml.template");
+const LIB_RS: &str = include_str!("../templates/day/lib.rs.template");
+const PART_RS: &str = include_str!("../templates/day/part.rs.template");
+
+#[derive(Args)]
+pub struct NewArgs {
+    /// Puzzle year, defaults to the most recent year with solutions
+    #[arg(long)]
+    year: Option<u16>,
+    /// Puzzle day, 1 to 25
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    /// Also download the input, and the examples from the puzzle page
+    #[arg(long)]
+    fetch: bool,
+}
+
+// 2023's crates predate the runner knowing about more than one year and keep their short
+// names, later years need the year in theirs since package names are shared by the workspace
+pub fn crate_name(year: u16, day: u8) -> String {
+    if year == 2023 {
+        format!("day-{day:02}")
+    } else {
+        format!("day-{year}-{day:02}")
+    }
+}
+
+fn fill(template: &str, year: u16, day: u8) -> String {
+    let name = crate_name(year, day);
+    template
+        .replace("{crate}", &name)
+        .replace("{lib}", &name.replace('-', "_"))
+        .replace("{solution}", &format!("Day{day:02}"))
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &day.to_string())
+}
+
+// the example a part's generated test reads, the first with an answer for that part. Without
+// one the test reads `p1.example` and fails until its answers file is filled in
+fn example_test(examples: &[Example], part: u8) -> &str {
+    examples
+        .iter()
+        .find(|example| example.answers.get(part).is_some())
+        .map_or("p1.example", |example| example.name.as_str())
+}
+
+// writes a new day's crate into `dir`, with `examples` in its `input/`
+pub fn generate(dir: &Path, year: u16, day: u8, examples: &[Example]) -> Result<()> {
+    if dir.exists() {
+        bail!("{} already exists", dir.display());
+    }
+    let bin = dir.join("src").join("bin");
+    fs::create_dir_all(&bin).with_context(|| format!("unable to create {}", bin.display()))?;
+    let mut lib = fill(LIB_RS, year, day);
+    for part in [1, 2] {
+        lib = lib.replace(&format!("{{example{part}}}"), example_test(examples, part));
+    }
+    let files = [
+        (dir.join("Cargo.toml"), fill(CARGO_TOML, year, day)),
+        (dir.join("src").join("lib.rs"), lib),
+        (
+            bin.join("part1.rs"),
+            fill(PART_RS, year, day).replace("{part}", "1"),
+        ),
+        (
+            bin.join("part2.rs"),
+            fill(PART_RS, year, day).replace("{part}", "2"),
+        ),
+    ];
+    for (path, contents) in files {
+        fs::write(&path, contents)
+            .with_context(|| format!("unable to write {}", path.display()))?;
+    }
+
+    let input = dir.join("input");
+    for example in examples {
+        examples::write_example(&input, example, false)?;
+    }
+    // an empty example to paste into, so the generated tests point somewhere
+    let example = input.join("p1.example.txt");
+    if !example.exists() {
+        fs::create_dir_all(&input)
+            .with_context(|| format!("unable to create {}", input.display()))?;
+        fs::write(&example, "")
+            .with_context(|| format!("unable to write {}", example.display()))?;
+    }
+    Ok(())
+}
+
+// adds `line` to the run of lines that `key` picks out, keeping it in calendar order
+fn insert_line(
+    contents: &str,
+    key: impl Fn(&str) -> Option<(u16, u8)>,
+    new: (u16, u8),
+    line: &str,
+) -> Option<String> {
+    let mut lines = contents.lines().collect::<Vec<_>>();
+    let keyed = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, key(line)?)))
+        .collect::<Vec<_>>();
+    let (last, _) = keyed.last()?;
+    let at = keyed
+        .iter()
+        .find(|(_, existing)| *existing > new)
+        .map_or(last + 1, |(i, _)| *i);
+    lines.insert(at, line);
+    Some(lines.join("\n") + "\n")
+}
+
+// `day-05 = { path = "../2023/day-05" }`
+fn dependency_key(line: &str) -> Option<(u16, u8)> {
+    let (_, path) = line.split_once("{ path = \"../")?;
+    let (year, rest) = path.split_once("/day-")?;
+    Some((year.parse().ok()?, rest.get(..2)?.parse().ok()?))
+}
+
+// `solver!(2023, 5, day_05::Day05),`
+fn solver_key(line: &str) -> Option<(u16, u8)> {
+    let args = line.trim_start().strip_prefix("solver!(")?;
+    let mut args = args.split(',').map(str::trim);
+    Some((args.next()?.parse().ok()?, args.next()?.parse().ok()?))
+}
+
+fn update(path: &Path, edit: impl FnOnce(&str) -> Option<String>) -> Result<()> {
+    let contents =
+        fs::read_to_string(path).with_context(|| format!("unable to read {}", path.display()))?;
+    let Some(contents) = edit(&contents) else {
+        bail!(
+            "unable to find where to register the day in {}",
+            path.display()
+        );
+    };
+    fs::write(path, contents).with_context(|| format!("unable to write {}", path.display()))
+}
+
+// makes the runner depend on the new day and adds it to the registry; the workspace already
+// picks it up through its `*/day-*` members glob
+pub fn register(root: &Path, year: u16, day: u8) -> Result<()> {
+    let name = crate_name(year, day);
+    let dependency = format!("{name} = {{ path = \"../{year}/day-{day:02}\" }}");
+    update(&root.join("runner").join("Cargo.toml"), |contents| {
+        insert_line(contents, dependency_key, (year, day), &dependency)
+    })?;
+    let solver = format!(
+        "    solver!({year}, {day}, {}::Day{day:02}),",
+        name.replace('-', "_")
+    );
+    update(
+        &root.join("runner").join("src").join("registry.rs"),
+        |contents| insert_line(contents, solver_key, (year, day), &solver),
+    )
+}
+
+pub fn run(args: NewArgs) -> Result<()> {
+    let year = args.year.unwrap_or_else(registry::latest_year);
+    let day = args.day;
+    let dir = registry::day_dir(year, day);
+    if dir.exists() {
+        bail!("{} already exists", dir.display());
+    }
+    let client = if args.fetch {
+        Some(Client::new(&Config::load()?))
+    } else {
+        None
+    };
+    let mut failures = 0;
+    let examples = match &client {
+        Some(client) => match client.puzzle(year, day) {
+            Ok(page) => examples::extract(&page),
+            Err(err) => {
+                eprintln!("{year} day {day:02}: {err:#}");
+                failures += 1;
+                Vec::new()
+            }
+        },
+        None => Vec::new(),
+    };
+
+    generate(&dir, year, day, &examples)?;
+    register(workspace_root(), year, day)?;
+    println!("{year} day {day:02}: created {}", dir.display());
+    for example in &examples {
+        println!("{year} day {day:02}: extracted {}.txt", example.name);
+    }
+    if let Some(client) = &client {
+        let path = dir.join("input").join("p1.txt");
+        match fetch_input(client, year, day, &path) {
+            Ok(_) => println!("{year} day {day:02}: downloaded the input"),
+            Err(err) => {
+                eprintln!("{year} day {day:02}: {err:#}");
+                failures += 1;
+            }
+        }
+    }
+    if failures > 0 {
+        bail!("the day was created, but {failures} download(s) failed");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::process::Command;
+
+    use super::*;
+
+    const PAGE: &str = include_str!("../fixtures/puzzle_page.html");
+
+    #[test]
+    fn names_crates_by_year() {
+        assert_eq!(crate_name(2023, 5), "day-05");
+        assert_eq!(crate_name(2024, 5), "day-2024-05");
+    }
+
+    #[test]
+    fn generates_a_day_from_the_template() {
+        let dir = tempfile::tempdir().expect("temp dir should be created");
+        let day = dir.path().join("day-2024-01");
+        generate(&day, 2024, 1, &examples::extract(PAGE)).expect("day should generate");
+
+        let cargo = fs::read_to_string(day.join("Cargo.toml")).expect("Cargo.toml should exist");
+        assert!(cargo.contains("name = \"day-2024-01\""));
+        assert!(cargo.contains("name = \"day-2024-01-part2\""));
+        let part2 =
+            fs::read_to_string(day.join("src/bin/part2.rs")).expect("part2.rs should exist");
+        assert!(part2.contains("use day_2024_01::Day01;"));
+        assert!(part2.contains("run_bin::<Day01>(2)"));
+        let lib = fs::read_to_string(day.join("src/lib.rs")).expect("lib.rs should exist");
+        assert!(lib.contains("pub struct Day01;"));
+        assert!(lib.contains("const YEAR: u16 = 2024;"));
+        assert!(lib.contains("let example = \"input/p1.example.txt\";"));
+        assert!(lib.contains("let example = \"input/p2.example2.txt\";"));
+        assert_eq!(lib.matches("#[ignore = ").count(), 2);
+        assert!(!lib.contains("{example"));
+        assert!(day.join("input/p2.example2.answers.toml").exists());
+        // the generated code should already be how `cargo fmt` leaves it
+        for file in ["src/lib.rs", "src/bin/part1.rs"] {
+            let status = Command::new("rustfmt")
+                .args(["--check", "--edition", "2021"])
+                .arg(day.join(file))
+                .status()
+                .expect("rustfmt should run");
+            assert!(status.success(), "{file} should be formatted");
+        }
+
+        generate(&day, 2024, 1, &[]).expect_err("an existing day should not be replaced");
+    }
+
+    #[test]
+    fn registers_in_calendar_order() {
+        let dir = tempfile::tempdir().expect("temp dir should be created");
+        let runner = dir.path().join("runner");
+        fs::create_dir_all(runner.join("src")).expect("runner dir should be created");
+        let real = workspace_root().join("runner");
+        for file in ["Cargo.toml", "src/registry.rs"] {
+            fs::copy(real.join(file), runner.join(file)).expect("runner file should copy");
+        }
+
+        register(dir.path(), 2024, 1).expect("day should register");
+        register(dir.path(), 2023, 25).expect("day should register");
+
+        let cargo = fs::read_to_string(runner.join("Cargo.toml")).expect("Cargo.toml exists");
+        assert!(cargo.contains(concat!(
+            "day-12 = { path = \"../2023/day-12\" }\n",
+            "day-25 = { path = \"../2023/day-25\" }\n",
+            "day-2024-01 = { path = \"../2024/day-01\" }\n",
+            "\n[features]"
+        )));
+        let registry = fs::read_to_string(runner.join("src/registry.rs")).expect("registry exists");
+        assert!(registry.contains(concat!(
+            "    solver!(2023, 12, day_12::Day12),\n",
+            "    solver!(2023, 25, day_25::Day25),\n",
+            "    solver!(2024, 1, day_2024_01::Day01),\n",
+            "];"
+        )));
+    }
+}
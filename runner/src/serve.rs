@@ -0,0 +1,329 @@
+// a small HTTP service, so scripts and people without a Rust toolchain can use the solvers:
+// `POST /{year}/{day}/{part}` with the puzzle input as the body answers with the same JSON as
+// `aoc run --format json`, and `GET /days` lists the registered days
+
+use std::{
+    io::Read,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc, Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, Result};
+use aoc_common::Report;
+use clap::Args;
+use serde::Serialize;
+use serde_json::json;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use crate::registry::{self, Solver, SOLVERS};
+
+#[derive(Args)]
+pub struct ServeArgs {
+    /// Port to listen on
+    #[arg(long, default_value_t = 8080)]
+    port: u16,
+    /// Address to listen on, only this machine by default
+    #[arg(long, default_value = "127.0.0.1")]
+    host: String,
+    /// Seconds a request may spend parsing and solving before it gets a 504
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+    timeout: u64,
+    /// Requests handled at once, and solves allowed to run at once including ones that timed
+    /// out; further solves get a 503 until one finishes
+    #[arg(long, default_value_t = 8, value_parser = clap::value_parser!(u16).range(1..))]
+    workers: u16,
+}
+
+// bigger than any real puzzle input, small enough not to matter if someone sends junk
+const MAX_BODY: u64 = 16 * 1024 * 1024;
+
+#[derive(Debug, Serialize)]
+struct Day {
+    year: u16,
+    day: u8,
+}
+
+// a status code and the JSON to send with it
+type Reply = (u16, String);
+
+fn error(status: u16, message: impl Into<String>) -> Reply {
+    (status, json!({ "error": message.into() }).to_string())
+}
+
+// how many solves may run at once. A solve holds its slot until its thread finishes, so
+// solves that timed out but are still running keep counting against the limit
+struct Slots {
+    busy: AtomicUsize,
+    size: usize,
+}
+
+// frees its slot when dropped
+struct Slot(Arc<Slots>);
+
+impl Slots {
+    fn new(size: usize) -> Arc<Slots> {
+        Arc::new(Slots {
+            busy: AtomicUsize::new(0),
+            size,
+        })
+    }
+
+    fn acquire(self: &Arc<Self>) -> Option<Slot> {
+        self.busy
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |busy| {
+                (busy < self.size).then_some(busy + 1)
+            })
+            .ok()
+            .map(|_| Slot(Arc::clone(self)))
+    }
+}
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        self.0.busy.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+// parses and solves on another thread, so a slow or stuck solver only holds up its own
+// request. Rust can't stop a thread, so one that times out carries on in the background,
+// holding its slot until it's done
+fn solve(
+    solver: &'static Solver,
+    part: u8,
+    input: String,
+    timeout: Duration,
+    slots: &Arc<Slots>,
+) -> Reply {
+    let Some(slot) = slots.acquire() else {
+        return error(503, "every worker is busy, try again later");
+    };
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let _slot = slot;
+        let start = Instant::now();
+        let parsed = match (solver.parse)(&input) {
+            Ok(parsed) => parsed,
+            Err(err) => return sender.send(Err(err)),
+        };
+        let parse = start.elapsed();
+        let start = Instant::now();
+        let answer = solver.part(part)(parsed.as_ref());
+        let solve = start.elapsed();
+        sender.send(answer.map(|answer| Report {
+            year: solver.year,
+            day: solver.day,
+            part,
+            answer,
+            parse: Some(parse),
+            solve,
+        }))
+    });
+    match receiver.recv_timeout(timeout) {
+        Ok(Ok(report)) => (
+            200,
+            serde_json::to_string(&report).expect("a report should always serialise"),
+        ),
+        Ok(Err(err)) => error(422, err.to_string()),
+        Err(mpsc::RecvTimeoutError::Timeout) => error(504, format!("no answer within {timeout:?}")),
+        Err(mpsc::RecvTimeoutError::Disconnected) => error(500, "the solver panicked"),
+    }
+}
+
+fn route(
+    method: &Method,
+    url: &str,
+    body: Vec<u8>,
+    timeout: Duration,
+    slots: &Arc<Slots>,
+) -> Reply {
+    let path = url.split('?').next().unwrap_or_default();
+    let segments = path
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<_>>();
+    match (method, segments.as_slice()) {
+        (Method::Get, ["days"]) => {
+            let days = SOLVERS
+                .iter()
+                .map(|solver| Day {
+                    year: solver.year,
+                    day: solver.day,
+                })
+                .collect::<Vec<_>>();
+            (
+                200,
+                serde_json::to_string(&days).expect("days should always serialise"),
+            )
+        }
+        (_, ["days"]) => error(405, "/days only answers GET"),
+        (Method::Post, [year, day, part]) => {
+            let (Ok(year), Ok(day), Ok(part)) =
+                (year.parse::<u16>(), day.parse::<u8>(), part.parse::<u8>())
+            else {
+                return error(404, format!("{path} isn't /{{year}}/{{day}}/{{part}}"));
+            };
+            if !(1..=2).contains(&part) {
+                return error(404, format!("puzzles only have two parts, got part {part}"));
+            }
+            let Some(solver) = registry::find(year, day) else {
+                return error(404, format!("no solution registered for {year} day {day}"));
+            };
+            match String::from_utf8(body) {
+                Ok(input) => solve(solver, part, input, timeout, slots),
+                Err(_) => error(400, "the puzzle input should be UTF-8"),
+            }
+        }
+        (_, [_, _, _]) => error(405, "solutions only answer POST"),
+        _ => error(404, format!("nothing at {path}")),
+    }
+}
+
+fn respond(mut request: Request, timeout: Duration, slots: &Arc<Slots>) {
+    let mut body = Vec::new();
+    let read = request
+        .as_reader()
+        .take(MAX_BODY + 1)
+        .read_to_end(&mut body);
+    let (status, json) = match read {
+        Err(err) => error(400, format!("unable to read the request: {err}")),
+        Ok(_) if body.len() as u64 > MAX_BODY => {
+            error(413, format!("inputs should be at most {MAX_BODY} bytes"))
+        }
+        Ok(_) => route(request.method(), request.url(), body, timeout, slots),
+    };
+    let header = Header::from_bytes("Content-Type", "application/json")
+        .expect("content type header should be valid");
+    let response = Response::from_string(json)
+        .with_status_code(status)
+        .with_header(header);
+    // the client may have given up waiting, and there's no one else to tell
+    let _ = request.respond(response);
+}
+
+// answers requests on `workers` threads until the server is dropped, with at most `workers`
+// solves running at once
+pub fn serve(server: Server, timeout: Duration, workers: usize) {
+    let slots = Slots::new(workers);
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| {
+                for request in server.incoming_requests() {
+                    respond(request, timeout, &slots);
+                }
+            });
+        }
+    });
+}
+
+pub fn run(args: ServeArgs) -> Result<()> {
+    let server = Server::http((args.host.as_str(), args.port))
+        .map_err(|err| anyhow!("unable to listen on {}:{}: {err}", args.host, args.port))?;
+    println!("listening on http://{}", server.server_addr());
+    serve(
+        server,
+        Duration::from_secs(args.timeout),
+        args.workers.into(),
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::read_input;
+
+    use super::*;
+
+    // serves on a free port for the rest of the test run
+    fn start() -> String {
+        let server = Server::http("127.0.0.1:0").expect("server should start");
+        let addr = server
+            .server_addr()
+            .to_ip()
+            .expect("server should listen on an ip address");
+        thread::spawn(move || serve(server, Duration::from_secs(10), 4));
+        format!("http://{addr}")
+    }
+
+    fn reply(result: Result<ureq::Response, ureq::Error>) -> (u16, serde_json::Value) {
+        let response = match result {
+            Ok(response) => response,
+            Err(ureq::Error::Status(_, response)) => response,
+            Err(err) => panic!("request should reach the server: {err}"),
+        };
+        let status = response.status();
+        let body = response.into_string().expect("reply should be readable");
+        let json = serde_json::from_str(&body).expect("reply should be JSON");
+        (status, json)
+    }
+
+    #[test]
+    fn solves_posted_inputs() {
+        let base = start();
+        let solver = registry::find(2023, 5).expect("day 5 should be registered");
+        let example = read_input(solver.example_input()).expect("example input should exist");
+
+        let (status, json) = reply(ureq::post(&format!("{base}/2023/5/2")).send_string(&example));
+        assert_eq!(status, 200, "{json}");
+        assert_eq!(json["answer"], "46");
+        assert_eq!(
+            (json["year"].clone(), json["day"].clone()),
+            (2023.into(), 5.into())
+        );
+        assert!(json["solve_ns"].is_u64());
+
+        let (status, json) = reply(ureq::get(&format!("{base}/days")).call());
+        assert_eq!(status, 200);
+        assert_eq!(json[4], json!({ "year": 2023, "day": 5 }));
+
+        let (status, json) = reply(ureq::post(&format!("{base}/2023/5/1")).send_string("junk"));
+        assert_eq!(status, 422);
+        assert!(json["error"]
+            .as_str()
+            .is_some_and(|err| err.starts_with("<input>:1:1:")));
+        let (status, _) = reply(ureq::post(&format!("{base}/2023/26/1")).send_string(""));
+        assert_eq!(status, 404);
+        let (status, _) = reply(ureq::post(&format!("{base}/2023/5/3")).send_string(""));
+        assert_eq!(status, 404);
+        let (status, _) = reply(ureq::get(&format!("{base}/2023/5/1")).call());
+        assert_eq!(status, 405);
+    }
+
+    fn slow_parse(_: &str) -> aoc_common::Result<Box<dyn std::any::Any>> {
+        thread::sleep(Duration::from_secs(5));
+        Ok(Box::new(()))
+    }
+
+    fn no_answer(_: &dyn std::any::Any) -> aoc_common::Result<String> {
+        Ok(String::new())
+    }
+
+    static SLOW: Solver = Solver {
+        year: 2023,
+        day: 26,
+        parse: slow_parse,
+        part1: no_answer,
+        part2: no_answer,
+        show: |_| String::new(),
+        render: None,
+        animate: None,
+        explore: None,
+    };
+
+    #[test]
+    fn slow_solvers_time_out() {
+        let start = Instant::now();
+        let slots = Slots::new(1);
+        let (status, json) = solve(&SLOW, 1, String::new(), Duration::from_millis(50), &slots);
+        assert_eq!(status, 504, "{json}");
+        assert!(start.elapsed() < Duration::from_secs(5));
+
+        // the timed-out solve is still running, so it still holds the only slot
+        let (status, json) = solve(&SLOW, 1, String::new(), Duration::from_millis(50), &slots);
+        assert_eq!(status, 503, "{json}");
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+}
@@ -0,0 +1,62 @@
+// a local HTTP server for tests, answering each request with the next canned response and
+// recording what it was sent
+
+use std::{
+    sync::mpsc::{self, Receiver},
+    thread,
+};
+
+use tiny_http::{Response, Server};
+
+#[derive(Debug)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub url: String,
+    pub cookie: Option<String>,
+    pub body: String,
+}
+
+pub struct StubServer {
+    pub base_url: String,
+    requests: Receiver<RecordedRequest>,
+}
+
+impl StubServer {
+    pub fn start(responses: Vec<(u16, &'static str)>) -> Self {
+        let server = Server::http("127.0.0.1:0").expect("stub server should start");
+        let addr = server
+            .server_addr()
+            .to_ip()
+            .expect("stub server should listen on an ip address");
+        let (sender, requests) = mpsc::channel();
+        thread::spawn(move || {
+            for (status, response) in responses {
+                let Ok(mut request) = server.recv() else {
+                    return;
+                };
+                let mut body = String::new();
+                let _ = request.as_reader().read_to_string(&mut body);
+                let cookie = request
+                    .headers()
+                    .iter()
+                    .find(|header| header.field.equiv("Cookie"))
+                    .map(|header| header.value.to_string());
+                let _ = sender.send(RecordedRequest {
+                    method: request.method().to_string(),
+                    url: request.url().to_string(),
+                    cookie,
+                    body,
+                });
+                let _ = request.respond(Response::from_string(response).with_status_code(status));
+            }
+        });
+        StubServer {
+            base_url: format!("http://{addr}"),
+            requests,
+        }
+    }
+
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.try_iter().collect()
+    }
+}
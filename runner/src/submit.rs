@@ -0,0 +1,315 @@
+use std::{fmt, fs, path::Path, time::Duration};
+
+use anyhow::{anyhow, bail, Context, Result};
+use aoc_common::read_input;
+use clap::Args;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    answers::Answers,
+    client::Client,
+    config::Config,
+    registry::{self, workspace_root},
+};
+
+#[derive(Args)]
+pub struct SubmitArgs {
+    /// Puzzle year, defaults to the most recent year with solutions
+    #[arg(long)]
+    year: Option<u16>,
+    /// Puzzle day, 1 to 25
+    #[arg(long)]
+    day: u8,
+    /// Which part the answer is for
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+    /// Answer to submit, otherwise the day is solved against its `input/p1.txt`
+    #[arg(long)]
+    answer: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    // the answer wasn't checked, so neither of these are recorded
+    // how long the site says to wait, if the page could be read
+    #[serde(skip)]
+    RateLimited(Option<Duration>),
+    #[serde(skip)]
+    WrongLevel,
+}
+
+impl Verdict {
+    fn is_checked(&self) -> bool {
+        !matches!(self, Verdict::RateLimited(_) | Verdict::WrongLevel)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Right => write!(f, "right"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::RateLimited(Some(wait)) => {
+                write!(f, "not checked, wait {wait:?} before retrying")
+            }
+            Verdict::RateLimited(None) => {
+                write!(f, "not checked, wait time unknown")
+            }
+            Verdict::WrongLevel => write!(f, "not checked, the part is locked or already solved"),
+        }
+    }
+}
+
+pub fn parse_verdict(page: &str) -> Option<Verdict> {
+    let verdict = if page.contains("That's the right answer") {
+        Verdict::Right
+    } else if page.contains("your answer is too high") {
+        Verdict::TooHigh
+    } else if page.contains("your answer is too low") {
+        Verdict::TooLow
+    } else if page.contains("That's not the right answer") {
+        Verdict::Wrong
+    } else if page.contains("You gave an answer too recently") {
+        Verdict::RateLimited(parse_wait(page))
+    } else if page.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        return None;
+    };
+    Some(verdict)
+}
+
+// reads the "You have 1m 25s left to wait" part of a rate limited response
+fn parse_wait(page: &str) -> Option<Duration> {
+    let (before, _) = page.split_once(" left to wait")?;
+    let (_, wait) = before.rsplit_once("You have ")?;
+    wait.split_whitespace()
+        .try_fold(0u64, |total, amount| {
+            let (num, secs) = [("s", 1), ("m", 60), ("h", 60 * 60)]
+                .into_iter()
+                .find_map(|(unit, secs)| Some((amount.strip_suffix(unit)?, secs)))?;
+            total.checked_add(num.parse::<u64>().ok()?.checked_mul(secs)?)
+        })
+        .map(Duration::from_secs)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+// every checked answer we've submitted, kept in `submissions.toml` at the workspace root
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct History {
+    #[serde(default, rename = "submission")]
+    pub submissions: Vec<Submission>,
+}
+
+impl History {
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(History::default());
+        }
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("unable to read {}", path.display()))?;
+        toml::from_str(&contents).with_context(|| format!("invalid submissions {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let contents = toml::to_string(self)?;
+        fs::write(path, contents).with_context(|| format!("unable to write {}", path.display()))
+    }
+
+    // why `answer` can't be right, going by what was said about earlier submissions
+    pub fn known_wrong(&self, year: u16, day: u8, part: u8, answer: &str) -> Option<String> {
+        let value = answer.parse::<i128>().ok();
+        self.submissions
+            .iter()
+            .filter(|s| s.year == year && s.day == day && s.part == part)
+            .find_map(|s| {
+                let bound = value.zip(s.answer.parse::<i128>().ok());
+                match s.verdict {
+                    Verdict::Right if s.answer != answer => {
+                        Some(format!("the part was already solved with {}", s.answer))
+                    }
+                    Verdict::Right => None,
+                    _ if s.answer == answer => Some(format!(
+                        "{answer} was already submitted and was {}",
+                        s.verdict
+                    )),
+                    Verdict::TooHigh if bound.is_some_and(|(value, high)| value >= high) => {
+                        Some(format!("{} was already too high", s.answer))
+                    }
+                    Verdict::TooLow if bound.is_some_and(|(value, low)| value <= low) => {
+                        Some(format!("{} was already too low", s.answer))
+                    }
+                    _ => None,
+                }
+            })
+    }
+}
+
+pub fn submit(
+    client: &Client,
+    history: &mut History,
+    (year, day, part): (u16, u8, u8),
+    answer: &str,
+) -> Result<Verdict> {
+    if let Some(reason) = history.known_wrong(year, day, part, answer) {
+        bail!("not submitting {answer}: {reason}");
+    }
+    let page = client.submit(year, day, part, answer)?;
+    let verdict = parse_verdict(&page).ok_or_else(|| anyhow!("unrecognised response:\n{page}"))?;
+    if verdict.is_checked() {
+        history.submissions.push(Submission {
+            year,
+            day,
+            part,
+            answer: answer.to_string(),
+            verdict,
+        });
+    }
+    Ok(verdict)
+}
+
+pub fn run(args: SubmitArgs) -> Result<()> {
+    let year = args.year.unwrap_or_else(registry::latest_year);
+    let (day, part) = (args.day, args.part);
+    let answer = match args.answer {
+        Some(answer) => answer,
+        None => {
+            let solver = registry::select(false, Some(year), Some(day))?[0];
+            let path = solver.default_input();
+            let input = read_input(&path)?;
+            let answers = solver
+                .solve(&input, &[part])
+                .map_err(|err| err.in_file(&path))?;
+            answers[0].clone()
+        }
+    };
+    let path = workspace_root().join("submissions.toml");
+    let mut history = History::load(&path)?;
+    let client = Client::new(&Config::load()?);
+    let verdict = submit(&client, &mut history, (year, day, part), &answer)?;
+    history.save(&path)?;
+    if verdict == Verdict::Right {
+        let path = Answers::path();
+        let mut answers = Answers::load(&path)?;
+        answers.set(year, day, part, &answer);
+        answers.save(&path)?;
+    }
+    println!("{year} day {day:02} part {part}: {answer} ({verdict})");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::stub::StubServer;
+
+    use super::*;
+
+    const RIGHT: &str = include_str!("../fixtures/answer_right.html");
+    const WRONG: &str = include_str!("../fixtures/answer_wrong.html");
+    const TOO_HIGH: &str = include_str!("../fixtures/answer_too_high.html");
+    const TOO_LOW: &str = include_str!("../fixtures/answer_too_low.html");
+    const RATE_LIMITED: &str = include_str!("../fixtures/answer_rate_limited.html");
+    const WRONG_LEVEL: &str = include_str!("../fixtures/answer_wrong_level.html");
+
+    fn client(server: &StubServer) -> Client {
+        Client::new(&Config {
+            session: Some("abc".to_string()),
+            base_url: Some(server.base_url.clone()),
+        })
+    }
+
+    #[test]
+    fn parses_verdicts() {
+        assert_eq!(parse_verdict(RIGHT), Some(Verdict::Right));
+        assert_eq!(parse_verdict(WRONG), Some(Verdict::Wrong));
+        assert_eq!(parse_verdict(TOO_HIGH), Some(Verdict::TooHigh));
+        assert_eq!(parse_verdict(TOO_LOW), Some(Verdict::TooLow));
+        assert_eq!(
+            parse_verdict(RATE_LIMITED),
+            Some(Verdict::RateLimited(Some(Duration::from_secs(85))))
+        );
+        assert_eq!(parse_verdict(WRONG_LEVEL), Some(Verdict::WrongLevel));
+        assert_eq!(parse_verdict("<html></html>"), None);
+    }
+
+    #[test]
+    fn records_and_refuses_known_wrong_answers() {
+        let server = StubServer::start(vec![(200, TOO_HIGH), (200, TOO_LOW), (200, RIGHT)]);
+        let client = client(&server);
+        let mut history = History::default();
+        let puzzle = (2023, 5, 2);
+
+        let verdict = submit(&client, &mut history, puzzle, "100").expect("should submit");
+        assert_eq!(verdict, Verdict::TooHigh);
+        submit(&client, &mut history, puzzle, "100").expect_err("100 is known to be wrong");
+        submit(&client, &mut history, puzzle, "150").expect_err("150 is above a too high answer");
+        let verdict = submit(&client, &mut history, puzzle, "20").expect("should submit");
+        assert_eq!(verdict, Verdict::TooLow);
+        let verdict = submit(&client, &mut history, puzzle, "46").expect("should submit");
+        assert_eq!(verdict, Verdict::Right);
+        submit(&client, &mut history, puzzle, "47").expect_err("the part is already solved");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].url, "/2023/day/5/answer");
+        assert_eq!(requests[0].body, "level=2&answer=100");
+        assert_eq!(history.submissions.len(), 3);
+    }
+
+    #[test]
+    fn unchecked_answers_are_not_recorded() {
+        let server = StubServer::start(vec![(200, RATE_LIMITED)]);
+        let mut history = History::default();
+        let verdict =
+            submit(&client(&server), &mut history, (2023, 5, 1), "35").expect("should submit");
+        assert!(matches!(verdict, Verdict::RateLimited(_)));
+        assert!(history.submissions.is_empty());
+
+        let dir = tempfile::tempdir().expect("temp dir should be created");
+        let path = dir.path().join("submissions.toml");
+        history.submissions.push(Submission {
+            year: 2023,
+            day: 5,
+            part: 1,
+            answer: "35".to_string(),
+            verdict: Verdict::TooLow,
+        });
+        history.save(&path).expect("history should save");
+        let loaded = History::load(&path).expect("history should load");
+        assert_eq!(loaded.submissions, history.submissions);
+    }
+
+    #[test]
+    fn unreadable_waits_are_unknown() {
+        let wait = |wait: &str| parse_wait(&format!("You have {wait} left to wait."));
+        assert_eq!(wait("1h 2m 3s"), Some(Duration::from_secs(3723)));
+        assert_eq!(wait("5é"), None);
+        assert_eq!(wait("1d"), None);
+        assert_eq!(wait("s"), None);
+        assert_eq!(wait(&format!("{}h", u64::MAX)), None);
+    }
+
+    #[test]
+    fn rate_limits_without_a_readable_wait() {
+        let page = RATE_LIMITED.replace("left to wait", "to go");
+        let verdict = parse_verdict(&page).expect("the page is still rate limited");
+        assert_eq!(verdict, Verdict::RateLimited(None));
+        assert_eq!(verdict.to_string(), "not checked, wait time unknown");
+    }
+}
@@ -0,0 +1,189 @@
+use std::{io, path::Path};
+
+use anyhow::{bail, Result};
+use aoc_common::{read_input, Error};
+use clap::Args;
+
+use crate::{
+    answers::Answers,
+    registry::{self, Solver},
+};
+
+#[derive(Args)]
+pub struct VerifyArgs {
+    /// Only verify this year
+    #[arg(long)]
+    year: Option<u16>,
+    /// Only verify this day
+    #[arg(long, requires = "year")]
+    day: Option<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Matched,
+    Mismatched { expected: String, actual: String },
+    Failed(String),
+    // the day's input hasn't been downloaded, so there's nothing to check the answer against
+    NoInput,
+}
+
+// checks every part of `solver` with a known answer against the input at `path`, or returns
+// `None` if none of its answers are known
+pub fn verify_day(solver: &Solver, answers: &Answers, path: &Path) -> Option<Vec<(u8, Outcome)>> {
+    let expected = [1, 2]
+        .into_iter()
+        .filter_map(|part| Some((part, answers.get(solver.year, solver.day, part)?)))
+        .collect::<Vec<_>>();
+    if expected.is_empty() {
+        return None;
+    }
+    let parts = expected.iter().map(|(part, _)| *part).collect::<Vec<_>>();
+    let every_part = |outcome: Outcome| {
+        parts
+            .iter()
+            .map(|part| (*part, outcome.clone()))
+            .collect::<Vec<_>>()
+    };
+    let input = match read_input(path) {
+        Ok(input) => input,
+        Err(Error::Io { source, .. }) if source.kind() == io::ErrorKind::NotFound => {
+            return Some(every_part(Outcome::NoInput));
+        }
+        Err(err) => return Some(every_part(Outcome::Failed(err.to_string()))),
+    };
+    let actual = match solver.solve(&input, &parts) {
+        Ok(actual) => actual,
+        Err(err) => return Some(every_part(Outcome::Failed(err.in_file(path).to_string()))),
+    };
+    let outcomes = expected
+        .into_iter()
+        .zip(actual)
+        .map(|((part, expected), actual)| {
+            let outcome = if expected == actual {
+                Outcome::Matched
+            } else {
+                Outcome::Mismatched {
+                    expected: expected.to_string(),
+                    actual,
+                }
+            };
+            (part, outcome)
+        })
+        .collect();
+    Some(outcomes)
+}
+
+pub fn run(args: VerifyArgs) -> Result<()> {
+    let answers = Answers::load(&Answers::path())?;
+    let mut checked = 0;
+    let mut failures = 0;
+    let mut skipped = 0;
+    for solver in registry::SOLVERS.iter().filter(|solver| {
+        args.year.is_none_or(|year| solver.year == year)
+            && args.day.is_none_or(|day| solver.day == day)
+    }) {
+        let Some(outcomes) = verify_day(solver, &answers, &solver.default_input()) else {
+            continue;
+        };
+        for (part, outcome) in outcomes {
+            let label = format!("{} day {:02} part {part}", solver.year, solver.day);
+            match outcome {
+                Outcome::NoInput => {
+                    skipped += 1;
+                    println!(
+                        "{label}: skipped, no input at {}",
+                        solver.default_input().display()
+                    );
+                    continue;
+                }
+                Outcome::Matched => println!("{label}: ok"),
+                Outcome::Mismatched { expected, actual } => {
+                    failures += 1;
+                    println!("{label}: expected {expected}, got {actual}");
+                }
+                Outcome::Failed(err) => {
+                    failures += 1;
+                    println!("{label}: {err}");
+                }
+            }
+            checked += 1;
+        }
+    }
+    if failures > 0 {
+        bail!("{failures} of {checked} known answer(s) did not match");
+    }
+    // answers that were all skipped shouldn't look like a pass
+    if checked == 0 && skipped > 0 {
+        bail!("none of the {skipped} known answer(s) could be checked, their inputs are missing");
+    }
+    println!("{checked} known answer(s) matched, {skipped} skipped without an input");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the same check as `aoc verify`, so `cargo test` catches a refactor that changes an answer.
+    // Days without their input are skipped, and the examples are checked by their own tests
+    #[test]
+    fn known_answers_still_match() {
+        let answers = Answers::load(&Answers::path()).expect("answers.toml should be valid");
+        for solver in registry::SOLVERS {
+            let outcomes = verify_day(solver, &answers, &solver.default_input());
+            for (part, outcome) in outcomes.unwrap_or_default() {
+                if outcome == Outcome::NoInput {
+                    continue;
+                }
+                assert_eq!(
+                    outcome,
+                    Outcome::Matched,
+                    "{} day {} part {part}",
+                    solver.year,
+                    solver.day
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn reports_mismatches() {
+        let solver = registry::find(2023, 5).expect("day 5 should be registered");
+        let path = solver.example_input();
+        let mut answers = Answers::default();
+        assert_eq!(verify_day(solver, &answers, &path), None);
+
+        answers.set(2023, 5, 1, "35");
+        answers.set(2023, 5, 2, "47");
+        let outcomes = verify_day(solver, &answers, &path).expect("answers should be checked");
+        assert_eq!(
+            outcomes,
+            vec![
+                (1, Outcome::Matched),
+                (
+                    2,
+                    Outcome::Mismatched {
+                        expected: "47".to_string(),
+                        actual: "46".to_string()
+                    }
+                )
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_inputs_are_skipped() {
+        let solver = registry::find(2023, 5).expect("day 5 should be registered");
+        let mut answers = Answers::default();
+        answers.set(2023, 5, 1, "35");
+        let dir = tempfile::tempdir().expect("temp dir should be created");
+        let outcomes = verify_day(solver, &answers, &dir.path().join("p1.txt"));
+        assert_eq!(outcomes, Some(vec![(1, Outcome::NoInput)]));
+        let outcomes = verify_day(solver, &answers, dir.path()).expect("answers should be checked");
+        assert!(
+            matches!(&outcomes[..], [(1, Outcome::Failed(err))] if err.starts_with("unable to read")),
+            "{outcomes:?}"
+        );
+    }
+}